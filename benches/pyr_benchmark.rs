@@ -2,6 +2,8 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use pyr::math::*;
 
 type Vector3 = F64Vector3;
+type Vector4 = F32Vector4;
+type Matrix4 = F32Matrix4;
 
 pub fn bench_mul_vec3_scalar(c: &mut Criterion) {
     let v = Vector3::new(1.0, 2.0, 3.0);
@@ -81,6 +83,112 @@ pub fn dot(c: &mut Criterion) {
     });
 }
 
+// Each SIMD benchmark is paired with a `generic` one running the same loop
+// through the generic operators, which is the scalar path every other element
+// type and dimension takes.
+const BATCH: usize = 1024;
+
+fn batch4(seed: f32) -> Vec<Vector4> {
+    (0..BATCH)
+        .map(|i| {
+            let t = i as f32 + seed;
+            Vector4::new(t.sin(), t.cos(), (t * 0.5).sin(), 1.0 + (t * 0.25).cos())
+        })
+        .collect()
+}
+
+pub fn add_vec4(c: &mut Criterion) {
+    let (va, vb) = (batch4(0.0), batch4(1.0));
+    c.bench_function("add f32 vector4 simd", |bench| {
+        bench.iter(|| {
+            let mut sum = Vector4::default();
+            for (&a, &b) in black_box(&va).iter().zip(black_box(&vb)) {
+                sum = sum.simd_add(a.simd_add(b));
+            }
+            sum
+        })
+    });
+    c.bench_function("add f32 vector4 generic", |bench| {
+        bench.iter(|| {
+            let mut sum = Vector4::default();
+            for (&a, &b) in black_box(&va).iter().zip(black_box(&vb)) {
+                sum += a + b;
+            }
+            sum
+        })
+    });
+}
+
+pub fn dot_vec4(c: &mut Criterion) {
+    let (va, vb) = (batch4(0.0), batch4(1.0));
+    c.bench_function("dot f32 vector4 simd", |bench| {
+        bench.iter(|| {
+            let mut sum = 0.0;
+            for (&a, &b) in black_box(&va).iter().zip(black_box(&vb)) {
+                sum += a.simd_dot(b);
+            }
+            sum
+        })
+    });
+    c.bench_function("dot f32 vector4 generic", |bench| {
+        bench.iter(|| {
+            let mut sum = 0.0;
+            for (&a, &b) in black_box(&va).iter().zip(black_box(&vb)) {
+                sum += a.dot(b);
+            }
+            sum
+        })
+    });
+}
+
+pub fn normalized_vec4(c: &mut Criterion) {
+    let va = batch4(0.0);
+    c.bench_function("normalize f32 vector4 simd", |bench| {
+        bench.iter(|| {
+            let mut sum = Vector4::default();
+            for &a in black_box(&va) {
+                sum = sum.simd_add(a.simd_normalized());
+            }
+            sum
+        })
+    });
+    c.bench_function("normalize f32 vector4 generic", |bench| {
+        bench.iter(|| {
+            let mut sum = Vector4::default();
+            for &a in black_box(&va) {
+                sum += a.normalized();
+            }
+            sum
+        })
+    });
+}
+
+pub fn mul_matrix4(c: &mut Criterion) {
+    let rows = batch4(0.0);
+    let vm: Vec<Matrix4> = rows
+        .chunks_exact(4)
+        .map(|r| Matrix4::from([r[0], r[1], r[2], r[3]]))
+        .collect();
+    c.bench_function("mul f32 matrix4 simd", |bench| {
+        bench.iter(|| {
+            let mut sum = Matrix4::default();
+            for pair in black_box(&vm).windows(2) {
+                sum = sum + pair[0].simd_mul(pair[1]);
+            }
+            sum
+        })
+    });
+    c.bench_function("mul f32 matrix4 generic", |bench| {
+        bench.iter(|| {
+            let mut sum = Matrix4::default();
+            for pair in black_box(&vm).windows(2) {
+                sum = sum + pair[0] * pair[1];
+            }
+            sum
+        })
+    });
+}
+
 criterion_group!(
     benches,
     bench_mul_vec3_scalar,
@@ -92,5 +200,9 @@ criterion_group!(
     length,
     normalized,
     dot,
+    add_vec4,
+    dot_vec4,
+    normalized_vec4,
+    mul_matrix4,
 );
 criterion_main!(benches);
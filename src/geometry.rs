@@ -1,3 +1,5 @@
+mod frustum;
 mod sphere;
 
+pub use frustum::*;
 pub use sphere::*;
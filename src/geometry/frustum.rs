@@ -0,0 +1,234 @@
+use super::Sphere;
+use crate::math::*;
+use num::Float;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Inside,
+    Intersecting,
+    Outside,
+}
+
+// Planes are stored as `[a, b, c, d]` with a unit normal `[a, b, c]` pointing
+// into the frustum, so `a * x + b * y + c * z + d` is the signed distance of a
+// point to the plane, positive inside. They are ordered left, right, bottom,
+// top, and then the two depth planes. The far plane of an infinite projection
+// becomes `[0, 0, 0, 1]`, which contains every point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum<T: MatrixElement + Float> {
+    planes: [Vector<T, 4>; 6],
+}
+
+impl<T: MatrixElement + Float> Frustum<T> {
+    // Extracts the planes of a view-projection matrix that maps points as
+    // `v * M`, with `depth_range` the NDC depth range it was built for.
+    pub fn from_matrix(view_proj: Matrix<T, 4, 4>, depth_range: DepthRange) -> Self {
+        let c = [
+            view_proj.col(0),
+            view_proj.col(1),
+            view_proj.col(2),
+            view_proj.col(3),
+        ];
+        let near = match depth_range {
+            DepthRange::NegativeOneToOne => c[3] + c[2],
+            DepthRange::ZeroToOne => c[2],
+        };
+        let planes = [
+            c[3] + c[0],
+            c[3] - c[0],
+            c[3] + c[1],
+            c[3] - c[1],
+            near,
+            c[3] - c[2],
+        ];
+        Self {
+            planes: planes.map(|plane| {
+                let len = plane.xyz().length();
+                if len.is_zero() {
+                    Vector::<T, 4>::unit_w()
+                } else {
+                    plane / len
+                }
+            }),
+        }
+    }
+
+    pub fn planes(&self) -> &[Vector<T, 4>; 6] {
+        &self.planes
+    }
+
+    fn distance(plane: Vector<T, 4>, p: Vector<T, 3>) -> T {
+        plane.xyz().dot(p) + plane.w()
+    }
+
+    pub fn contains_point(&self, p: impl VectorLike<T, 3>) -> bool {
+        let p = p.to_vector();
+        self.planes
+            .iter()
+            .all(|&plane| Self::distance(plane, p) >= T::zero())
+    }
+
+    pub fn classify_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        let center = sphere.center().coords();
+        let mut result = Containment::Inside;
+        for &plane in &self.planes {
+            let d = Self::distance(plane, center);
+            if d < -sphere.radius() {
+                return Containment::Outside;
+            }
+            if d < sphere.radius() {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        self.classify_sphere(sphere) != Containment::Outside
+    }
+
+    // Like the sphere test, a box that crosses the planes of the frustum only
+    // outside of it may be reported as intersecting.
+    pub fn classify_box(
+        &self,
+        min: impl VectorLike<T, 3>,
+        max: impl VectorLike<T, 3>,
+    ) -> Containment {
+        let (min, max) = (min.to_vector(), max.to_vector());
+        let mut result = Containment::Inside;
+        for &plane in &self.planes {
+            let normal = plane.xyz();
+            let mut farthest = min;
+            let mut nearest = max;
+            for i in 0..3 {
+                if normal[i] >= T::zero() {
+                    farthest[i] = max[i];
+                    nearest[i] = min[i];
+                }
+            }
+            if Self::distance(plane, farthest) < T::zero() {
+                return Containment::Outside;
+            }
+            if Self::distance(plane, nearest) < T::zero() {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_box(&self, min: impl VectorLike<T, 3>, max: impl VectorLike<T, 3>) -> bool {
+        self.classify_box(min, max) != Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num::Zero;
+
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    const DEPTH_RANGES: [DepthRange; 2] = [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne];
+
+    // A camera at the origin looking down `-Z` with a 90 degree field of view,
+    // so the side planes are `|x| <= -z` and `|y| <= -z`.
+    fn frustums() -> Vec<Frustum<f64>> {
+        let view = Matrix4::look_at_rh(Vector3::zero(), -Vector3::unit_z(), Vector3::unit_y());
+        let fovy = Deg(90.0);
+        let mut frustums = Vec::new();
+        for depth_range in DEPTH_RANGES {
+            for projection in [
+                Matrix4::perspective_fov_rh(fovy, 1.0, 1.0, 100.0, depth_range),
+                Matrix4::perspective_fov_reverse_z_rh(fovy, 1.0, 1.0, 100.0, depth_range),
+            ] {
+                frustums.push(Frustum::from_matrix(view * projection, depth_range));
+            }
+        }
+        frustums
+    }
+
+    #[test]
+    fn planes_are_normalized() {
+        for frustum in frustums() {
+            for plane in frustum.planes() {
+                assert!((plane.xyz().length() - 1.0).abs() < 1.0e-12);
+            }
+            let near = frustum.planes()[4];
+            let far = frustum.planes()[5];
+            let near_and_far = [near.w().abs(), far.w().abs()];
+            assert!(near_and_far.iter().any(|d| (d - 1.0).abs() < 1.0e-9));
+            assert!(near_and_far.iter().any(|d| (d - 100.0).abs() < 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn contains_point() {
+        for frustum in frustums() {
+            assert!(frustum.contains_point([0.0, 0.0, -10.0]));
+            assert!(frustum.contains_point([4.0, -4.0, -5.0]));
+            assert!(!frustum.contains_point([6.0, 0.0, -5.0]));
+            assert!(!frustum.contains_point([0.0, 0.0, -0.5]));
+            assert!(!frustum.contains_point([0.0, 0.0, -101.0]));
+            assert!(!frustum.contains_point([0.0, 0.0, 10.0]));
+        }
+    }
+
+    #[test]
+    fn classify_sphere() {
+        for frustum in frustums() {
+            let sphere = Sphere::new([0.0, 0.0, -10.0], 1.0);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Inside);
+            let sphere = Sphere::new([10.0, 0.0, -10.0], 1.0);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Intersecting);
+            let sphere = Sphere::new([0.0, 0.0, -100.0], 2.0);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Intersecting);
+            let sphere = Sphere::new([20.0, 0.0, -10.0], 1.0);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Outside);
+            assert!(!frustum.intersects_sphere(&sphere));
+            let sphere = Sphere::new([0.0, 0.0, 5.0], 1.0);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Outside);
+        }
+    }
+
+    #[test]
+    fn classify_box() {
+        for frustum in frustums() {
+            let c = frustum.classify_box([-1.0, -1.0, -11.0], [1.0, 1.0, -9.0]);
+            assert_eq!(c, Containment::Inside);
+            let c = frustum.classify_box([-1.0, -1.0, -11.0], [20.0, 1.0, -9.0]);
+            assert_eq!(c, Containment::Intersecting);
+            let c = frustum.classify_box([-1.0, -1.0, -200.0], [1.0, 1.0, -50.0]);
+            assert_eq!(c, Containment::Intersecting);
+            let c = frustum.classify_box([15.0, -1.0, -11.0], [20.0, 1.0, -9.0]);
+            assert_eq!(c, Containment::Outside);
+            assert!(!frustum.intersects_box([-1.0, -1.0, 1.0], [1.0, 1.0, 2.0]));
+            assert!(frustum.intersects_box([-50.0, -50.0, -50.0], [50.0, 50.0, 50.0]));
+        }
+    }
+
+    #[test]
+    fn infinite_and_orthographic() {
+        let view = Matrix4::look_at_rh(Vector3::zero(), -Vector3::unit_z(), Vector3::unit_y());
+        for depth_range in DEPTH_RANGES {
+            let projection = Matrix4::perspective_fov_infinite_rh(Deg(90.0), 1.0, 1.0, depth_range);
+            let frustum = Frustum::from_matrix(view * projection, depth_range);
+            assert!(frustum.contains_point([0.0, 0.0, -1.0e6]));
+            assert!(!frustum.contains_point([0.0, 0.0, -0.5]));
+
+            let projection =
+                Matrix4::perspective_fov_infinite_reverse_z_rh(Deg(90.0), 1.0, 1.0, depth_range);
+            let frustum = Frustum::from_matrix(view * projection, depth_range);
+            assert!(frustum.contains_point([0.0, 0.0, -1.0e6]));
+            assert!(!frustum.contains_point([0.0, 0.0, -0.5]));
+
+            let projection = Matrix4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0, depth_range);
+            let frustum = Frustum::from_matrix(view * projection, depth_range);
+            assert!(frustum.contains_point([1.5, 0.5, -9.0]));
+            assert!(!frustum.contains_point([1.5, 1.5, -9.0]));
+            assert!(!frustum.contains_point([0.0, 0.0, -11.0]));
+            let sphere = Sphere::new([0.0, 0.0, -5.0], 0.5);
+            assert_eq!(frustum.classify_sphere(&sphere), Containment::Inside);
+        }
+    }
+}
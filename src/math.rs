@@ -1,10 +1,34 @@
+pub mod angle;
+#[cfg(test)]
+pub(crate) mod approx;
+pub mod bivector;
+pub mod coordinates;
+pub mod lie;
 pub mod matrix;
+pub mod normal;
+pub mod optics;
+pub mod point;
 pub mod quaternion;
+pub mod rotation2;
+pub mod screen;
+mod simd;
+pub mod transform;
 pub mod vector;
+pub mod wide;
 
+pub use self::angle::{Deg, Rad};
+pub use self::bivector::Bivector3;
+pub use self::lie::{Se3, So3};
 pub use self::matrix::*;
+pub use self::normal::Normal;
+pub use self::optics::*;
+pub use self::point::Point;
 pub use self::quaternion::Quaternion;
+pub use self::rotation2::Rotation2;
+pub use self::screen::*;
+pub use self::transform::{Affine3, Isometry3, Similarity3};
 pub use self::vector::*;
+pub use self::wide::{WideVector3, WideVector3x4, WideVector3x8};
 
 impl<T: MatrixElement, const ROW: usize, const COL: usize> std::ops::Mul<Matrix<T, ROW, COL>>
     for Vector<T, ROW>
@@ -20,6 +44,17 @@ impl<T: MatrixElement, const ROW: usize, const COL: usize> std::ops::Mul<Matrix<
     }
 }
 
+impl<T: MatrixElement, const N: usize> Vector<T, N> {
+    pub fn outer<const M: usize>(&self, other: impl VectorLike<T, M>) -> Matrix<T, N, M> {
+        let other = other.to_vector();
+        let mut m = Matrix::<T, N, M>::default();
+        for i in 0..N {
+            m[i] = other * self[i];
+        }
+        m
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -31,6 +66,14 @@ mod test {
         assert_eq!(v * m, [40, 52].into());
     }
 
+    #[test]
+    fn outer() {
+        let v1: Vector<i32, 3> = [1, 2, 3].into();
+        let v2: Vector<i32, 2> = [4, 5].into();
+        assert_eq!(v1.outer(v2), [[4, 5], [8, 10], [12, 15]].to_matrix());
+        assert_eq!(v2.outer([1, 2, 3]), [[4, 8, 12], [5, 10, 15]].to_matrix());
+    }
+
     #[test]
     fn rotate_2x2() {
         let m = Matrix::<f64, 2, 2>::rotate(45.0_f64.to_radians());
@@ -40,6 +83,14 @@ mod test {
         assert!((rot_v.x() - result.x()).abs() < 1.0e-10);
         assert!((rot_v.y() - result.y()).abs() < 1.0e-10);
     }
+
+    #[test]
+    fn rotate_2x2_degrees() {
+        let m = Matrix::<f64, 2, 2>::rotate(Deg(45.0));
+        let v = Vector::<f64, 2>::unit_y();
+        let result = Vector::<f64, 2>::new(-1.0, 1.0).normalized();
+        approx::assert_approx_eq(v * m, result, 1.0e-10);
+    }
 }
 
 pub type F32Vector1 = Vector<f32, 1>;
@@ -0,0 +1,256 @@
+use num::Float;
+use std::ops;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rad<T: Float>(pub T);
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Deg<T: Float>(pub T);
+
+// A bare value is taken as radians, so functions accepting
+// `impl Into<Rad<T>>` still accept plain floats.
+impl<T: Float> From<T> for Rad<T> {
+    fn from(value: T) -> Self {
+        Rad(value)
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    fn from(deg: Deg<T>) -> Self {
+        Rad(deg.0.to_radians())
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    fn from(rad: Rad<T>) -> Self {
+        Deg(rad.0.to_degrees())
+    }
+}
+
+impl<T: Float> Rad<T> {
+    pub fn full_turn() -> Self {
+        Deg::full_turn().into()
+    }
+
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+}
+
+impl<T: Float> Deg<T> {
+    pub fn full_turn() -> Self {
+        Deg(T::from(360).unwrap())
+    }
+}
+
+macro_rules! impl_angle {
+    ($angle: ident) => {
+        impl<T: Float> $angle<T> {
+            pub fn zero() -> Self {
+                $angle(T::zero())
+            }
+
+            pub fn half_turn() -> Self {
+                Self::full_turn() / (T::one() + T::one())
+            }
+
+            // In `[0, full_turn)`.
+            pub fn normalize(self) -> Self {
+                let full = Self::full_turn().0;
+                let mut r = self.0 % full;
+                if r < T::zero() {
+                    r = r + full;
+                }
+                if r >= full {
+                    r = T::zero();
+                }
+                $angle(r)
+            }
+
+            // In `(-half_turn, half_turn]`.
+            pub fn normalize_signed(self) -> Self {
+                let r = self.normalize();
+                if r > Self::half_turn() {
+                    r - Self::full_turn()
+                } else {
+                    r
+                }
+            }
+
+            // The signed angle of the shortest rotation from `self` to `other`.
+            pub fn difference(self, other: Self) -> Self {
+                (other - self).normalize_signed()
+            }
+
+            // Interpolates along the shortest rotation, so the result is not
+            // normalized.
+            pub fn lerp(self, other: Self, t: T) -> Self {
+                self + self.difference(other) * t
+            }
+        }
+
+        impl<T: Float> ops::Add for $angle<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $angle(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Float> ops::Sub for $angle<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $angle(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: Float> ops::Neg for $angle<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $angle(-self.0)
+            }
+        }
+
+        impl<T: Float> ops::Mul<T> for $angle<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $angle(self.0 * rhs)
+            }
+        }
+
+        impl<T: Float> ops::Div<T> for $angle<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $angle(self.0 / rhs)
+            }
+        }
+
+        impl<T: Float> ops::Div for $angle<T> {
+            type Output = T;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+
+        impl<T: Float> ops::AddAssign for $angle<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Float> ops::SubAssign for $angle<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Float> ops::MulAssign<T> for $angle<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Float> ops::DivAssign<T> for $angle<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
+
+impl_angle!(Rad);
+impl_angle!(Deg);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    fn assert_rad_eq(a: Rad<f64>, b: f64) {
+        assert!((a.0 - b).abs() < 1.0e-10, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn conversion() {
+        assert_rad_eq(Deg(180.0).into(), PI);
+        assert_rad_eq(Rad::from(0.5), 0.5);
+        let d: Deg<f64> = Rad(FRAC_PI_2).into();
+        assert!((d.0 - 90.0).abs() < 1.0e-10);
+        assert_rad_eq(Rad::full_turn(), TAU);
+        assert_eq!(Deg::<f32>::half_turn(), Deg(180.0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Deg(30.0) + Deg(60.0) - Deg(10.0);
+        assert_eq!(a, Deg(80.0));
+        assert_eq!(-a, Deg(-80.0));
+        assert_eq!(a * 2.0, Deg(160.0));
+        assert_eq!(a / 4.0, Deg(20.0));
+        assert_eq!(a / Deg(40.0), 2.0);
+        a += Deg(10.0);
+        a -= Deg(30.0);
+        a *= 3.0;
+        a /= 2.0;
+        assert_eq!(a, Deg(90.0));
+        assert!(Rad(0.1) < Rad(0.2));
+        assert_eq!(Rad(FRAC_PI_2).sin(), 1.0);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Deg(370.0).normalize(), Deg(10.0));
+        assert_eq!(Deg(-10.0).normalize(), Deg(350.0));
+        assert_eq!(Deg(720.0).normalize(), Deg(0.0));
+        assert_eq!(Deg(360.0).normalize(), Deg(0.0));
+        assert_rad_eq(Rad(-FRAC_PI_2).normalize(), 3.0 * FRAC_PI_2);
+        assert_eq!(Deg(-1.0e-20).normalize(), Deg(0.0));
+    }
+
+    #[test]
+    fn normalize_signed() {
+        assert_eq!(Deg(190.0).normalize_signed(), Deg(-170.0));
+        assert_eq!(Deg(-190.0).normalize_signed(), Deg(170.0));
+        assert_eq!(Deg(180.0).normalize_signed(), Deg(180.0));
+        assert_eq!(Deg(-180.0).normalize_signed(), Deg(180.0));
+        assert_rad_eq(Rad(3.0 * PI / 2.0).normalize_signed(), -FRAC_PI_2);
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(Deg(10.0).difference(Deg(350.0)), Deg(-20.0));
+        assert_eq!(Deg(350.0).difference(Deg(10.0)), Deg(20.0));
+        assert_eq!(Deg(-170.0).difference(Deg(170.0)), Deg(-20.0));
+    }
+
+    #[test]
+    fn lerp() {
+        assert_eq!(Deg(350.0).lerp(Deg(10.0), 0.5).normalize(), Deg(0.0));
+        assert_eq!(Deg(10.0).lerp(Deg(50.0), 0.25), Deg(20.0));
+        assert_rad_eq(Rad(0.0).lerp(Rad(FRAC_PI_2), 1.0), FRAC_PI_2);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::math::matrix::*;
+use crate::math::normal::Normal;
+use crate::math::point::Point;
+use crate::math::vector::*;
+use num::Float;
+use std::fmt::Debug;
+
+// Tolerance-based comparison shared by the test modules.
+pub(crate) trait ApproxEq: Copy + Debug {
+    type Scalar: Float + Debug;
+
+    fn distance_to(&self, other: &Self) -> Self::Scalar;
+}
+
+impl<T, const DIMENSION: usize> ApproxEq for Vector<T, DIMENSION>
+where
+    T: VectorElement + Float + Debug,
+{
+    type Scalar = T;
+
+    fn distance_to(&self, other: &Self) -> T {
+        (*self - *other).length()
+    }
+}
+
+impl<T, const ROW: usize, const COL: usize> ApproxEq for Matrix<T, ROW, COL>
+where
+    T: MatrixElement + Float + Debug,
+{
+    type Scalar = T;
+
+    fn distance_to(&self, other: &Self) -> T {
+        (0..ROW).fold(T::zero(), |d, r| d.max(self[r].distance_to(&other[r])))
+    }
+}
+
+impl<T, const DIMENSION: usize> ApproxEq for Point<T, DIMENSION>
+where
+    T: VectorElement + Float + Debug,
+{
+    type Scalar = T;
+
+    fn distance_to(&self, other: &Self) -> T {
+        self.coords().distance_to(&other.coords())
+    }
+}
+
+impl<T, const DIMENSION: usize> ApproxEq for Normal<T, DIMENSION>
+where
+    T: VectorElement + Float + Debug,
+{
+    type Scalar = T;
+
+    fn distance_to(&self, other: &Self) -> T {
+        self.vector().distance_to(&other.vector())
+    }
+}
+
+// What `assert_approx_eq` accepts as the expected value. A narrower `Into`
+// with only the conversions the tests need, so the element type of an
+// expected value like `Vector3::unit_x()` is inferred from the actual one.
+pub(crate) trait Expected<A> {
+    fn expected(self) -> A;
+}
+
+impl<A: ApproxEq> Expected<A> for A {
+    fn expected(self) -> A {
+        self
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Expected<Vector<T, DIMENSION>> for [T; DIMENSION] {
+    fn expected(self) -> Vector<T, DIMENSION> {
+        self.into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Expected<Point<T, DIMENSION>> for [T; DIMENSION] {
+    fn expected(self) -> Point<T, DIMENSION> {
+        self.into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Expected<Point<T, DIMENSION>>
+    for Vector<T, DIMENSION>
+{
+    fn expected(self) -> Point<T, DIMENSION> {
+        self.into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Expected<Normal<T, DIMENSION>> for [T; DIMENSION] {
+    fn expected(self) -> Normal<T, DIMENSION> {
+        self.into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Expected<Normal<T, DIMENSION>>
+    for Vector<T, DIMENSION>
+{
+    fn expected(self) -> Normal<T, DIMENSION> {
+        self.into()
+    }
+}
+
+pub(crate) fn assert_approx_eq<A: ApproxEq>(a: A, b: impl Expected<A>, tolerance: A::Scalar) {
+    let b = b.expected();
+    assert!(a.distance_to(&b) < tolerance, "{a:?} != {b:?}");
+}
@@ -0,0 +1,121 @@
+use crate::math::vector::*;
+use num::Float;
+use std::ops;
+
+// An oriented plane element `xy e12 + yz e23 + zx e31`. Its dual is the
+// vector `[yz, zx, xy]`, so `a.wedge(b).dual()` equals `a.cross(b)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Bivector3<T: VectorElement> {
+    pub xy: T,
+    pub yz: T,
+    pub zx: T,
+}
+
+impl<T: VectorElement + Eq> Eq for Bivector3<T> {}
+
+impl<T: VectorElement> Bivector3<T> {
+    pub fn new(xy: T, yz: T, zx: T) -> Self {
+        Self { xy, yz, zx }
+    }
+
+    pub fn from_dual(v: impl VectorLike<T, 3>) -> Self {
+        Self::new(*v.get(2), *v.get(0), *v.get(1))
+    }
+
+    pub fn dual(&self) -> Vector<T, 3> {
+        Vector::<T, 3>::new(self.yz, self.zx, self.xy)
+    }
+
+    pub fn magnitude_squared(&self) -> T {
+        self.xy * self.xy + self.yz * self.yz + self.zx * self.zx
+    }
+}
+
+impl<T: VectorElement + Float> Bivector3<T> {
+    // The area of the parallelogram spanned by the two wedged vectors.
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+}
+
+impl<T: VectorElement> Vector<T, 3> {
+    pub fn wedge(&self, other: impl VectorLike<T, 3>) -> Bivector3<T> {
+        let (a, b) = (self, other.to_vector());
+        Bivector3::new(
+            a.x() * b.y() - a.y() * b.x(),
+            a.y() * b.z() - a.z() * b.y(),
+            a.z() * b.x() - a.x() * b.z(),
+        )
+    }
+}
+
+impl<T: VectorElement> ops::Add for Bivector3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.xy + rhs.xy, self.yz + rhs.yz, self.zx + rhs.zx)
+    }
+}
+
+impl<T: VectorElement> ops::Sub for Bivector3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.xy - rhs.xy, self.yz - rhs.yz, self.zx - rhs.zx)
+    }
+}
+
+impl<T: VectorElement + ops::Neg<Output = T>> ops::Neg for Bivector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.xy, -self.yz, -self.zx)
+    }
+}
+
+impl<T: VectorElement> ops::Mul<T> for Bivector3<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.xy * scalar, self.yz * scalar, self.zx * scalar)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Vector3 = Vector<i32, 3>;
+
+    #[test]
+    fn wedge() {
+        let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+        assert_eq!(x.wedge(y), Bivector3::new(1, 0, 0));
+        assert_eq!(y.wedge(z), Bivector3::new(0, 1, 0));
+        assert_eq!(z.wedge(x), Bivector3::new(0, 0, 1));
+        assert_eq!(y.wedge(x), -x.wedge(y));
+        assert_eq!(x.wedge(x), Bivector3::default());
+    }
+
+    #[test]
+    fn dual() {
+        let a = Vector3::new(2, -1, 5);
+        let b = Vector3::new(-3, 4, 1);
+        assert_eq!(a.wedge(b).dual(), a.cross(b));
+        assert_eq!(Bivector3::from_dual(a.cross(b)), a.wedge(b));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Bivector3::new(1, 2, 3);
+        let b = Bivector3::new(4, -5, 6);
+        assert_eq!(a + b, Bivector3::new(5, -3, 9));
+        assert_eq!(a - b, Bivector3::new(-3, 7, -3));
+        assert_eq!(a * 2, Bivector3::new(2, 4, 6));
+        assert_eq!(a.magnitude_squared(), 14);
+
+        let area = Vector::<f64, 3>::new(2.0, 0.0, 0.0).wedge([0.0, 3.0, 0.0]);
+        assert_eq!(area.magnitude(), 6.0);
+    }
+}
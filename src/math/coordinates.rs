@@ -0,0 +1,327 @@
+use crate::math::angle::Rad;
+use crate::math::vector::*;
+use num::Float;
+
+// Polar coordinates are `(r, theta)` with `theta` measured from `+X` towards
+// `+Y` in `(-pi, pi]`.
+impl<T: VectorElement + Float> Vector<T, 2> {
+    pub fn from_polar(r: T, theta: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = theta.into().sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    pub fn to_polar(&self) -> (T, Rad<T>) {
+        (self.length(), Rad(self.y().atan2(self.x())))
+    }
+}
+
+// Spherical coordinates follow the ISO convention `(r, theta, phi)`: `theta`
+// is the polar angle from `+Z` in `[0, pi]` and `phi` the azimuth from `+X`
+// towards `+Y` in `(-pi, pi]`. Cylindrical coordinates are `(rho, phi, z)`
+// with the same azimuth.
+impl<T: VectorElement + Float> Vector<T, 3> {
+    pub fn from_spherical(r: T, theta: impl Into<Rad<T>>, phi: impl Into<Rad<T>>) -> Self {
+        let (sin_theta, cos_theta) = theta.into().sin_cos();
+        let (sin_phi, cos_phi) = phi.into().sin_cos();
+        Self::new(
+            r * sin_theta * cos_phi,
+            r * sin_theta * sin_phi,
+            r * cos_theta,
+        )
+    }
+
+    pub fn to_spherical(&self) -> (T, Rad<T>, Rad<T>) {
+        let rho = self.x().hypot(self.y());
+        (
+            self.length(),
+            Rad(rho.atan2(self.z())),
+            Rad(self.y().atan2(self.x())),
+        )
+    }
+
+    pub fn from_cylindrical(rho: T, phi: impl Into<Rad<T>>, z: T) -> Self {
+        let (sin, cos) = phi.into().sin_cos();
+        Self::new(rho * cos, rho * sin, z)
+    }
+
+    pub fn to_cylindrical(&self) -> (T, Rad<T>, T) {
+        (
+            self.x().hypot(self.y()),
+            Rad(self.y().atan2(self.x())),
+            self.z(),
+        )
+    }
+
+    // Maps a direction onto the octahedron `|x| + |y| + |z| = 1`, unfolds the
+    // lower half over the corners and quantizes the result to 16 bits per
+    // component. Need not be normalized. Panics if the direction is zero or
+    // not finite.
+    pub fn to_octahedral(&self) -> Vector<u16, 2> {
+        let l1 = self.x().abs() + self.y().abs() + self.z().abs();
+        assert!(
+            l1.is_finite() && l1 > T::zero(),
+            "direction must be finite and non-zero"
+        );
+        let sign = |e: T| if e < T::zero() { -T::one() } else { T::one() };
+        let p = *self / l1;
+        let (mut x, mut y) = (p.x(), p.y());
+        if p.z() < T::zero() {
+            (x, y) = (
+                (T::one() - y.abs()) * sign(x),
+                (T::one() - x.abs()) * sign(y),
+            );
+        }
+        let max = T::from(u16::MAX).unwrap();
+        let half = (T::one() + T::one()).recip();
+        let quantize = |e: T| ((e * half + half) * max).round().to_u16().unwrap();
+        Vector::<u16, 2>::new(quantize(x), quantize(y))
+    }
+
+    pub fn from_octahedral(encoded: Vector<u16, 2>) -> Self {
+        let max = T::from(u16::MAX).unwrap();
+        let two = T::one() + T::one();
+        let x = T::from(encoded.x()).unwrap() / max * two - T::one();
+        let y = T::from(encoded.y()).unwrap() / max * two - T::one();
+        let z = T::one() - x.abs() - y.abs();
+        let t = (-z).max(T::zero());
+        let x = if x >= T::zero() { x - t } else { x + t };
+        let y = if y >= T::zero() { y - t } else { y + t };
+        Self::new(x, y, z).normalized()
+    }
+
+    // The `index`-th of `count` points spread evenly over the unit sphere
+    // along a Fibonacci spiral running from `+Z` to `-Z`. Panics if `count`
+    // is zero.
+    pub fn from_spherical_fibonacci(index: u32, count: u32) -> Self {
+        assert!(count > 0, "count must not be zero");
+        let i = T::from(index).unwrap();
+        let n = T::from(count).unwrap();
+        let two = T::one() + T::one();
+        let z = T::one() - (two * i + T::one()) / n;
+        let phi = Rad::<T>::full_turn().0 * fract(i * golden_ratio_conjugate());
+        let r = (T::one() - z * z).max(T::zero()).sqrt();
+        Self::new(r * phi.cos(), r * phi.sin(), z)
+    }
+
+    // The index of the point of `from_spherical_fibonacci` closest to this
+    // direction, which must be normalized. Keinert et al., "Spherical
+    // Fibonacci Mapping", 2015. Panics if `count` is zero.
+    pub fn to_spherical_fibonacci(&self, count: u32) -> u32 {
+        assert!(count > 0, "count must not be zero");
+        let one = T::one();
+        let two = one + one;
+        let five = T::from(5).unwrap();
+        let full_turn = Rad::<T>::full_turn().0;
+        let n = T::from(count).unwrap();
+        let conjugate = golden_ratio_conjugate::<T>();
+        let golden_ratio = conjugate + one;
+
+        let phi = self.y().atan2(self.x());
+        let cos_theta = self.z();
+        let k = ((n * full_turn / two * five.sqrt() * (one - cos_theta * cos_theta)).ln()
+            / (golden_ratio * golden_ratio).ln())
+        .floor()
+        .max(two);
+        let fk = golden_ratio.powf(k) / five.sqrt();
+        let f = [fk.round(), (fk * golden_ratio).round()];
+
+        // The columns are the steps in `(phi, z)` from moving `f[0]` and `f[1]`
+        // points along the spiral.
+        let step = |f: T| full_turn * (fract((f + one) * conjugate) - conjugate);
+        let b = [[step(f[0]), step(f[1])], [-two * f[0] / n, -two * f[1] / n]];
+        let det = b[0][0] * b[1][1] - b[0][1] * b[1][0];
+        let dz = cos_theta - (one - one / n);
+        let c = [
+            ((b[1][1] * phi - b[0][1] * dz) / det).floor(),
+            ((b[0][0] * dz - b[1][0] * phi) / det).floor(),
+        ];
+
+        let last = n - one;
+        let mut nearest = (T::infinity(), T::zero());
+        for (u, v) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let i = f[0] * (c[0] + T::from(u).unwrap()) + f[1] * (c[1] + T::from(v).unwrap());
+            let i = i.max(T::zero()).min(last);
+            let q = Self::from_spherical_fibonacci(i.to_u32().unwrap(), count);
+            let distance = (q - *self).length_squared();
+            if distance < nearest.0 {
+                nearest = (distance, i);
+            }
+        }
+        nearest.1.to_u32().unwrap()
+    }
+}
+
+fn fract<T: Float>(x: T) -> T {
+    x - x.floor()
+}
+
+// `1 / golden_ratio`, which equals `golden_ratio - 1`.
+fn golden_ratio_conjugate<T: Float>() -> T {
+    let two = T::one() + T::one();
+    (T::from(5).unwrap().sqrt() - T::one()) / two
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Deg;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    type Vector2 = Vector<f64, 2>;
+    type Vector3 = Vector<f64, 3>;
+
+    fn directions() -> Vec<Vector3> {
+        let mut directions = vec![
+            Vector3::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_z(),
+            -Vector3::unit_x(),
+            -Vector3::unit_y(),
+            -Vector3::unit_z(),
+        ];
+        for i in 0..50 {
+            let t = i as f64;
+            directions.push(
+                Vector3::new((t * 1.3).sin(), (t * 0.7).cos(), (t * 2.1).sin() - 0.3).normalized(),
+            );
+        }
+        directions
+    }
+
+    #[test]
+    fn polar() {
+        let v = Vector2::from_polar(2.0, Deg(90.0));
+        assert_approx_eq(v, Vector2::new(0.0, 2.0), 1.0e-12);
+
+        let (r, theta) = Vector2::new(-1.0, -1.0).to_polar();
+        assert!((r - 2.0_f64.sqrt()).abs() < 1.0e-12);
+        assert!((theta.0 + 3.0 * FRAC_PI_4).abs() < 1.0e-12);
+        assert_eq!(Vector2::new(-1.0, 0.0).to_polar().1 .0, PI);
+
+        let v = Vector2::new(3.0, -4.0);
+        let (r, theta) = v.to_polar();
+        assert_approx_eq(Vector2::from_polar(r, theta), v, 1.0e-12);
+    }
+
+    #[test]
+    fn spherical() {
+        let v = Vector3::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2);
+        assert_approx_eq(v, Vector3::new(0.0, 2.0, 0.0), 1.0e-12);
+        let v = Vector3::from_spherical(1.0, 0.0, 1.0);
+        assert_approx_eq(v, Vector3::unit_z(), 1.0e-12);
+
+        let (r, theta, phi) = Vector3::new(1.0, 1.0, 2.0_f64.sqrt()).to_spherical();
+        assert!((r - 2.0).abs() < 1.0e-12);
+        assert!((theta.0 - FRAC_PI_4).abs() < 1.0e-12);
+        assert!((phi.0 - FRAC_PI_4).abs() < 1.0e-12);
+        assert_eq!(Vector3::new(0.0, 0.0, -3.0).to_spherical().1 .0, PI);
+
+        for v in directions() {
+            let v = v * 3.0;
+            let (r, theta, phi) = v.to_spherical();
+            assert!((0.0..=PI).contains(&theta.0));
+            assert_approx_eq(Vector3::from_spherical(r, theta, phi), v, 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn cylindrical() {
+        let v = Vector3::from_cylindrical(2.0, Deg(180.0), 5.0);
+        assert_approx_eq(v, Vector3::new(-2.0, 0.0, 5.0), 1.0e-12);
+
+        let (rho, phi, z) = Vector3::new(0.0, -3.0, 1.5).to_cylindrical();
+        assert_eq!(rho, 3.0);
+        assert!((phi.0 + FRAC_PI_2).abs() < 1.0e-12);
+        assert_eq!(z, 1.5);
+
+        for v in directions() {
+            let (rho, phi, z) = v.to_cylindrical();
+            assert_approx_eq(Vector3::from_cylindrical(rho, phi, z), v, 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn octahedral() {
+        assert_eq!(
+            Vector3::unit_z().to_octahedral(),
+            Vector::<u16, 2>::new(32768, 32768)
+        );
+        assert_eq!(
+            Vector3::unit_x().to_octahedral(),
+            Vector::<u16, 2>::new(u16::MAX, 32768)
+        );
+        assert_eq!(
+            (-Vector3::unit_z()).to_octahedral(),
+            Vector::<u16, 2>::new(u16::MAX, u16::MAX)
+        );
+
+        for v in directions() {
+            let decoded = Vector3::from_octahedral(v.to_octahedral());
+            assert!((decoded.length() - 1.0).abs() < 1.0e-12);
+            assert_approx_eq(decoded, v, 1.0e-4);
+
+            let decoded = Vector::<f32, 3>::from_octahedral(v.to_octahedral());
+            assert!((decoded.x() as f64 - v.x()).abs() < 1.0e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "direction must be finite and non-zero")]
+    fn octahedral_zero() {
+        Vector3::new(0.0, 0.0, 0.0).to_octahedral();
+    }
+
+    #[test]
+    #[should_panic(expected = "direction must be finite and non-zero")]
+    fn octahedral_nan() {
+        Vector3::new(f64::NAN, 0.0, 1.0).to_octahedral();
+    }
+
+    #[test]
+    fn spherical_fibonacci() {
+        for count in [1, 2, 7, 100, 1000, 1 << 16] {
+            let step = (count / 97).max(1);
+            for index in (0..count).step_by(step as usize) {
+                let v = Vector3::from_spherical_fibonacci(index, count);
+                assert!((v.length() - 1.0).abs() < 1.0e-12);
+                assert_eq!(v.to_spherical_fibonacci(count), index);
+            }
+        }
+
+        // The encoded index matches a brute force search for the nearest point.
+        let count = 200;
+        for v in directions() {
+            let nearest = (0..count)
+                .min_by(|&a, &b| {
+                    let da = (Vector3::from_spherical_fibonacci(a, count) - v).length_squared();
+                    let db = (Vector3::from_spherical_fibonacci(b, count) - v).length_squared();
+                    da.partial_cmp(&db).unwrap()
+                })
+                .unwrap();
+            assert_eq!(v.to_spherical_fibonacci(count), nearest);
+        }
+    }
+    #[test]
+    fn spherical_fibonacci_f32() {
+        for count in [1, 2, 7, 100, 1000] {
+            for index in 0..count {
+                let v = Vector::<f32, 3>::from_spherical_fibonacci(index, count);
+                assert!((v.length() - 1.0).abs() < 1.0e-6);
+                assert_eq!(v.to_spherical_fibonacci(count), index);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "count must not be zero")]
+    fn from_spherical_fibonacci_zero_count() {
+        Vector3::from_spherical_fibonacci(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "count must not be zero")]
+    fn to_spherical_fibonacci_zero_count() {
+        Vector3::unit_z().to_spherical_fibonacci(0);
+    }
+}
@@ -0,0 +1,542 @@
+use crate::math::matrix::*;
+use crate::math::point::Point;
+use crate::math::quaternion::Quaternion;
+use crate::math::transform::Isometry3;
+use crate::math::vector::*;
+use num::{Float, Zero};
+use std::ops::Mul;
+
+// Groups compose like the transforms in `transform`: `a * b` applies `a`
+// first and then `b`. Every matrix follows the row-vector convention, so a
+// tangent vector `tau` is mapped by a Jacobian or an adjoint as `tau * J`,
+// and `v * hat(omega)` equals `omega.cross(v)`.
+//
+// `plus` and `minus` perturb in the local frame: `x.plus(tau)` equals
+// `exp(tau) * x`, which applies `exp(tau)` before `x`, and
+// `x.plus(tau).minus(x)` equals `tau`. The right Jacobian `jr` of `tau`
+// satisfies `exp(tau + d) ~ exp(d * jr) * exp(tau)` and the left Jacobian
+// `jl` satisfies `exp(tau + d) ~ exp(tau) * exp(d * jl)`.
+
+fn two<T: Float>() -> T {
+    T::one() + T::one()
+}
+
+// Below this angle the closed forms lose precision and their Taylor
+// expansions are used instead.
+fn small_angle<T: Float>() -> T {
+    T::epsilon().sqrt().sqrt()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct So3<T: MatrixElement + Float> {
+    quaternion: Quaternion<T>,
+}
+
+impl<T: MatrixElement + Float> Default for So3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: MatrixElement + Float> So3<T> {
+    pub fn identity() -> Self {
+        Self {
+            quaternion: Quaternion::identity(),
+        }
+    }
+
+    pub fn from_quaternion(q: Quaternion<T>) -> Self {
+        Self {
+            quaternion: q.normalized(),
+        }
+    }
+
+    pub fn from_matrix(m: Matrix<T, 3, 3>) -> Self {
+        Self::from_quaternion(Quaternion::from_matrix3(m))
+    }
+
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.quaternion
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 3, 3> {
+        self.quaternion.to_matrix3()
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            quaternion: self.quaternion.conjugate(),
+        }
+    }
+
+    pub fn rotate_vector(&self, v: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        self.quaternion.rotate_vector(v)
+    }
+
+    pub fn exp(omega: impl VectorLike<T, 3>) -> Self {
+        let omega = omega.to_vector();
+        let theta = omega.length();
+        let half = theta / two();
+        let (s, c) = half.sin_cos();
+        let k = if theta < small_angle() {
+            T::one() / two() - theta * theta / T::from(48).unwrap()
+        } else {
+            s / theta
+        };
+        let v = omega * k;
+        Self::from_quaternion(Quaternion::new(v.x(), v.y(), v.z(), c))
+    }
+
+    // The angle of the result is in `[0, pi]`.
+    pub fn log(&self) -> Vector<T, 3> {
+        let q = if self.quaternion.w < T::zero() {
+            -self.quaternion
+        } else {
+            self.quaternion
+        };
+        let v = q.vector_part();
+        let n = v.length();
+        let k = if n < small_angle() {
+            two::<T>() / q.w * (T::one() - n * n / (T::from(3).unwrap() * q.w * q.w))
+        } else {
+            two::<T>() * n.atan2(q.w) / n
+        };
+        v * k
+    }
+
+    pub fn hat(omega: impl VectorLike<T, 3>) -> Matrix<T, 3, 3> {
+        let (x, y, z) = (*omega.get(0), *omega.get(1), *omega.get(2));
+        let o = T::zero();
+        [[o, z, -y], [-z, o, x], [y, -x, o]].into()
+    }
+
+    pub fn vee(m: Matrix<T, 3, 3>) -> Vector<T, 3> {
+        Vector::<T, 3>::new(m[1][2], m[2][0], m[0][1])
+    }
+
+    pub fn adjoint(&self) -> Matrix<T, 3, 3> {
+        self.to_matrix()
+    }
+
+    pub fn right_jacobian(omega: impl VectorLike<T, 3>) -> Matrix<T, 3, 3> {
+        Self::left_jacobian(-omega.to_vector())
+    }
+
+    pub fn left_jacobian(omega: impl VectorLike<T, 3>) -> Matrix<T, 3, 3> {
+        let omega = omega.to_vector();
+        let theta2 = omega.length_squared();
+        let theta = theta2.sqrt();
+        let (a, b) = if theta < small_angle() {
+            (
+                T::one() / two() - theta2 / T::from(24).unwrap(),
+                T::one() / T::from(6).unwrap() - theta2 / T::from(120).unwrap(),
+            )
+        } else {
+            let (s, c) = theta.sin_cos();
+            ((T::one() - c) / theta2, (theta - s) / (theta2 * theta))
+        };
+        let h = Self::hat(omega);
+        Matrix::identity() + h * a + h * h * b
+    }
+
+    pub fn right_jacobian_inverse(omega: impl VectorLike<T, 3>) -> Matrix<T, 3, 3> {
+        Self::left_jacobian_inverse(-omega.to_vector())
+    }
+
+    pub fn left_jacobian_inverse(omega: impl VectorLike<T, 3>) -> Matrix<T, 3, 3> {
+        let omega = omega.to_vector();
+        let theta2 = omega.length_squared();
+        let theta = theta2.sqrt();
+        let b = if theta < small_angle() {
+            T::one() / T::from(12).unwrap() + theta2 / T::from(720).unwrap()
+        } else {
+            let (s, c) = theta.sin_cos();
+            T::one() / theta2 - (T::one() + c) / (two::<T>() * theta * s)
+        };
+        let h = Self::hat(omega);
+        Matrix::identity() - h / two() + h * h * b
+    }
+
+    pub fn plus(&self, tau: impl VectorLike<T, 3>) -> Self {
+        Self::exp(tau) * *self
+    }
+
+    pub fn minus(&self, other: Self) -> Vector<T, 3> {
+        (*self * other.inverse()).log()
+    }
+}
+
+impl<T: MatrixElement + Float> Mul<Self> for So3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_quaternion(rhs.quaternion * self.quaternion)
+    }
+}
+
+impl<T: MatrixElement + Float> From<Quaternion<T>> for So3<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        Self::from_quaternion(q)
+    }
+}
+
+impl<T: MatrixElement + Float> From<So3<T>> for Quaternion<T> {
+    fn from(r: So3<T>) -> Self {
+        r.quaternion
+    }
+}
+
+// Tangent vectors are `[rho, theta]`: the translational part first and the
+// rotational part last.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Se3<T: MatrixElement + Float> {
+    pub rotation: So3<T>,
+    pub translation: Vector<T, 3>,
+}
+
+impl<T: MatrixElement + Float> Default for Se3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+fn blocks<T: MatrixElement + Float>(
+    a: Matrix<T, 3, 3>,
+    b: Matrix<T, 3, 3>,
+    c: Matrix<T, 3, 3>,
+    d: Matrix<T, 3, 3>,
+) -> Matrix<T, 6, 6> {
+    let mut m = Matrix::<T, 6, 6>::zero();
+    for r in 0..3 {
+        for col in 0..3 {
+            m[r][col] = a[r][col];
+            m[r][col + 3] = b[r][col];
+            m[r + 3][col] = c[r][col];
+            m[r + 3][col + 3] = d[r][col];
+        }
+    }
+    m
+}
+
+impl<T: MatrixElement + Float> Se3<T> {
+    pub fn new(rotation: So3<T>, translation: impl VectorLike<T, 3>) -> Self {
+        Self {
+            rotation,
+            translation: translation.to_vector(),
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(So3::identity(), Vector::<T, 3>::zero())
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        Isometry3::from(*self).to_matrix()
+    }
+
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self::new(rotation, -rotation.rotate_vector(self.translation))
+    }
+
+    pub fn transform_point(&self, p: Point<T, 3>) -> Point<T, 3> {
+        Point::from(self.rotation.rotate_vector(p.coords())) + self.translation
+    }
+
+    pub fn exp(tau: impl VectorLike<T, 6>) -> Self {
+        let (rho, theta): (Vector<T, 3>, Vector<T, 3>) = tau.to_vector().split_at();
+        Self::new(So3::exp(theta), rho * So3::left_jacobian(theta))
+    }
+
+    pub fn log(&self) -> Vector<T, 6> {
+        let theta = self.rotation.log();
+        let rho = self.translation * So3::left_jacobian_inverse(theta);
+        rho.concat(theta)
+    }
+
+    pub fn hat(tau: impl VectorLike<T, 6>) -> Matrix<T, 4, 4> {
+        let (rho, theta): (Vector<T, 3>, Vector<T, 3>) = tau.to_vector().split_at();
+        let h = So3::hat(theta);
+        let mut m = Matrix::<T, 4, 4>::zero();
+        for r in 0..3 {
+            for c in 0..3 {
+                m[r][c] = h[r][c];
+            }
+            m[3][r] = rho[r];
+        }
+        m
+    }
+
+    pub fn vee(m: Matrix<T, 4, 4>) -> Vector<T, 6> {
+        let rho = m[3].xyz();
+        let theta = Vector::<T, 3>::new(m[1][2], m[2][0], m[0][1]);
+        rho.concat(theta)
+    }
+
+    pub fn adjoint(&self) -> Matrix<T, 6, 6> {
+        let r = self.rotation.to_matrix();
+        blocks(r, Matrix::zero(), r * So3::hat(self.translation), r)
+    }
+
+    pub fn right_jacobian(tau: impl VectorLike<T, 6>) -> Matrix<T, 6, 6> {
+        Self::left_jacobian(-tau.to_vector())
+    }
+
+    pub fn left_jacobian(tau: impl VectorLike<T, 6>) -> Matrix<T, 6, 6> {
+        let (rho, theta): (Vector<T, 3>, Vector<T, 3>) = tau.to_vector().split_at();
+        let j = So3::left_jacobian(theta);
+        blocks(j, Matrix::zero(), Self::q(rho, theta), j)
+    }
+
+    pub fn right_jacobian_inverse(tau: impl VectorLike<T, 6>) -> Matrix<T, 6, 6> {
+        Self::left_jacobian_inverse(-tau.to_vector())
+    }
+
+    pub fn left_jacobian_inverse(tau: impl VectorLike<T, 6>) -> Matrix<T, 6, 6> {
+        let (rho, theta): (Vector<T, 3>, Vector<T, 3>) = tau.to_vector().split_at();
+        let j = So3::left_jacobian_inverse(theta);
+        let q = Matrix::<T, 3, 3>::zero() - j * Self::q(rho, theta) * j;
+        blocks(j, Matrix::zero(), q, j)
+    }
+
+    // The coupling block of the left Jacobian (Barfoot, "State Estimation for
+    // Robotics", 7.86), computed with column-vector hats and transposed.
+    fn q(rho: Vector<T, 3>, theta: Vector<T, 3>) -> Matrix<T, 3, 3> {
+        let t2 = theta.length_squared();
+        let t = t2.sqrt();
+        let (a, b, c) = if t < small_angle() {
+            (
+                T::one() / T::from(6).unwrap() - t2 / T::from(120).unwrap(),
+                T::one() / T::from(24).unwrap() - t2 / T::from(720).unwrap(),
+                T::one() / T::from(120).unwrap() - t2 / T::from(2520).unwrap(),
+            )
+        } else {
+            let (s, cos) = t.sin_cos();
+            let t4 = t2 * t2;
+            (
+                (t - s) / (t2 * t),
+                (t2 + two::<T>() * cos - two()) / (two::<T>() * t4),
+                (two::<T>() * t - T::from(3).unwrap() * s + t * cos) / (two::<T>() * t4 * t),
+            )
+        };
+        let p = So3::hat(theta).transpose();
+        let r = So3::hat(rho).transpose();
+        let prp = p * r * p;
+        let q = r / two()
+            + (p * r + r * p + prp) * a
+            + (p * p * r + r * p * p - prp * T::from(3).unwrap()) * b
+            + (prp * p + p * prp) * c;
+        q.transpose()
+    }
+
+    pub fn plus(&self, tau: impl VectorLike<T, 6>) -> Self {
+        Self::exp(tau) * *self
+    }
+
+    pub fn minus(&self, other: Self) -> Vector<T, 6> {
+        (*self * other.inverse()).log()
+    }
+}
+
+impl<T: MatrixElement + Float> Mul<Self> for Se3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.rotation * rhs.rotation,
+            rhs.rotation.rotate_vector(self.translation) + rhs.translation,
+        )
+    }
+}
+
+impl<T: MatrixElement + Float> From<Isometry3<T>> for Se3<T> {
+    fn from(iso: Isometry3<T>) -> Self {
+        Self::new(iso.rotation.into(), iso.translation)
+    }
+}
+
+impl<T: MatrixElement + Float> From<Se3<T>> for Isometry3<T> {
+    fn from(se3: Se3<T>) -> Self {
+        Isometry3::new(se3.rotation.into(), se3.translation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+
+    type Vector3 = Vector<f64, 3>;
+    type Vector6 = Vector<f64, 6>;
+
+    fn omegas() -> [Vector3; 5] {
+        [
+            Vector3::new(0.3, -0.2, 0.5),
+            Vector3::new(1.0, 2.0, -0.5),
+            Vector3::new(0.0, 0.0, 3.0),
+            Vector3::new(1.0e-5, -2.0e-5, 0.0),
+            Vector3::zero(),
+        ]
+    }
+
+    fn taus() -> [Vector6; 4] {
+        [
+            [0.5, -1.0, 2.0, 0.3, -0.2, 0.5].into(),
+            [-3.0, 0.2, 1.0, 1.0, 2.0, -0.5].into(),
+            [1.0, 2.0, 3.0, 1.0e-5, 0.0, -1.0e-5].into(),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0].into(),
+        ]
+    }
+
+    #[test]
+    fn so3_exp_log() {
+        for omega in omegas() {
+            assert_approx_eq(So3::exp(omega).log(), omega, 1.0e-12);
+            let r = So3::exp(omega);
+            assert_approx_eq(So3::exp(r.log()).to_matrix(), r.to_matrix(), 1.0e-9);
+        }
+        let r = So3::exp([0.0, 0.0, std::f64::consts::FRAC_PI_2]);
+        assert_approx_eq(
+            r.rotate_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-12,
+        );
+    }
+
+    #[test]
+    fn so3_matches_quaternion() {
+        let omega = Vector3::new(1.0, 2.0, -0.5);
+        let q = Quaternion::from_axis_angle(omega, omega.length());
+        assert!((So3::exp(omega).quaternion().dot(q) - 1.0).abs() < 1.0e-12);
+        let a = So3::exp([0.3, 0.1, 0.0]);
+        let b = So3::exp([0.0, -0.4, 0.8]);
+        assert_approx_eq((a * b).to_matrix(), a.to_matrix() * b.to_matrix(), 1.0e-9);
+        assert_approx_eq((a * a.inverse()).to_matrix(), Matrix::identity(), 1.0e-9);
+    }
+
+    #[test]
+    fn so3_hat_vee() {
+        let omega = Vector3::new(1.0, -2.0, 3.0);
+        let v = Vector3::new(0.5, 4.0, -1.0);
+        assert_eq!(v * So3::hat(omega), omega.cross(v));
+        assert_eq!(So3::vee(So3::hat(omega)), omega);
+    }
+
+    #[test]
+    fn so3_adjoint() {
+        let x = So3::exp([0.3, -1.0, 0.5]);
+        let tau = Vector3::new(0.1, 0.2, -0.3);
+        let lhs = So3::exp(tau) * x;
+        let rhs = x * So3::exp(tau * x.adjoint());
+        assert_approx_eq(lhs.to_matrix(), rhs.to_matrix(), 1.0e-9);
+    }
+
+    #[test]
+    fn so3_plus_minus() {
+        let x = So3::exp([0.3, -1.0, 0.5]);
+        for omega in omegas() {
+            assert_approx_eq(x.plus(omega).minus(x), omega, 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn so3_jacobians() {
+        let d = Vector3::new(1.0e-6, -2.0e-6, 1.5e-6);
+        for omega in omegas() {
+            let jr = So3::right_jacobian(omega);
+            let jl = So3::left_jacobian(omega);
+            let r = So3::exp(omega + d).minus(So3::exp(omega));
+            assert_approx_eq(r, d * jr, 1.0e-11);
+            let l = (So3::exp(omega).inverse() * So3::exp(omega + d)).log();
+            assert_approx_eq(l, d * jl, 1.0e-11);
+            assert_approx_eq(
+                jr * So3::right_jacobian_inverse(omega),
+                Matrix::identity(),
+                1.0e-9,
+            );
+            assert_approx_eq(
+                jl * So3::left_jacobian_inverse(omega),
+                Matrix::identity(),
+                1.0e-9,
+            );
+        }
+    }
+
+    #[test]
+    fn se3_exp_log() {
+        for tau in taus() {
+            assert_approx_eq(Se3::exp(tau).log(), tau, 1.0e-10);
+        }
+        let x = Se3::exp([0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(x.translation, Vector3::unit_z());
+        assert_approx_eq(Se3::exp(Vector6::zero()).log(), Vector6::zero(), 1.0e-15);
+    }
+
+    #[test]
+    fn se3_matches_isometry() {
+        let a = Se3::exp(taus()[0]);
+        let b = Se3::exp(taus()[1]);
+        let p = Point::<f64, 3>::new(0.5, 4.0, -1.0);
+        let m = (a * b).to_matrix();
+        assert_approx_eq(m, a.to_matrix() * b.to_matrix(), 1.0e-9);
+        assert_approx_eq(
+            (a * b).transform_point(p),
+            m.transform_point(p.coords()),
+            1.0e-12,
+        );
+        assert_eq!(a.transform_point(p), Isometry3::from(a).transform_point(p));
+        assert_approx_eq((a * a.inverse()).log(), Vector6::zero(), 1.0e-12);
+        assert_eq!(Se3::from(Isometry3::from(a)), a);
+    }
+
+    #[test]
+    fn se3_hat_vee() {
+        let tau = taus()[0];
+        let (rho, theta): (Vector3, Vector3) = tau.split_at();
+        let p = Vector3::new(0.5, 4.0, -1.0);
+        let h = Se3::hat(tau);
+        let v = Vector::<f64, 4>::new(p.x(), p.y(), p.z(), 1.0) * h;
+        assert_approx_eq(v.xyz(), theta.cross(p) + rho, 1.0e-12);
+        assert_eq!(Se3::vee(h), tau);
+    }
+
+    #[test]
+    fn se3_adjoint() {
+        let x = Se3::exp(taus()[1]);
+        let tau = Vector6::from([0.1, 0.2, -0.3, 0.05, -0.1, 0.2]);
+        let lhs = Se3::exp(tau) * x;
+        let rhs = x * Se3::exp(tau * x.adjoint());
+        assert_approx_eq(lhs.to_matrix(), rhs.to_matrix(), 1.0e-9);
+    }
+
+    #[test]
+    fn se3_plus_minus() {
+        let x = Se3::exp(taus()[1]);
+        for tau in taus() {
+            assert_approx_eq(x.plus(tau).minus(x), tau, 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn se3_jacobians() {
+        let d = Vector6::from([1.0e-6, -2.0e-6, 1.5e-6, 0.5e-6, 1.0e-6, -1.0e-6]);
+        for tau in taus() {
+            let jr = Se3::right_jacobian(tau);
+            let jl = Se3::left_jacobian(tau);
+            let r = Se3::exp(tau + d).minus(Se3::exp(tau));
+            assert_approx_eq(r, d * jr, 1.0e-10);
+            let l = (Se3::exp(tau).inverse() * Se3::exp(tau + d)).log();
+            assert_approx_eq(l, d * jl, 1.0e-10);
+            assert_approx_eq(
+                jr * Se3::right_jacobian_inverse(tau),
+                Matrix::identity(),
+                1.0e-9,
+            );
+            assert_approx_eq(
+                jl * Se3::left_jacobian_inverse(tau),
+                Matrix::identity(),
+                1.0e-9,
+            );
+        }
+    }
+}
@@ -1,8 +1,17 @@
+mod cast;
+mod decompose;
+mod iter;
+mod projection;
 mod traits;
+mod transform;
+mod view;
 
+pub use self::decompose::*;
+pub use self::projection::*;
 pub use self::traits::*;
+use crate::math::angle::Rad;
 use crate::math::vector::*;
-use num::{Float, One, Zero};
+use num::{CheckedAdd, CheckedMul, CheckedSub, Float, Integer, One, Zero};
 use std::ops::*;
 
 #[repr(C)]
@@ -56,12 +65,79 @@ impl<T: MatrixElement, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
         }
         v
     }
+
+    pub fn transpose(&self) -> Matrix<T, COL, ROW> {
+        let mut m = Matrix::<T, COL, ROW>::default();
+        for r in 0..ROW {
+            for c in 0..COL {
+                m.elements[c][r] = self.elements[r][c];
+            }
+        }
+        m
+    }
+
+    pub fn map<U: MatrixElement>(self, mut f: impl FnMut(T) -> U) -> Matrix<U, ROW, COL> {
+        Matrix {
+            elements: self.elements.map(|row| row.map(&mut f)),
+        }
+    }
+
+    pub fn zip_map<U, R>(
+        self,
+        other: Matrix<U, ROW, COL>,
+        mut f: impl FnMut(T, U) -> R,
+    ) -> Matrix<R, ROW, COL>
+    where
+        U: MatrixElement,
+        R: MatrixElement,
+    {
+        let mut m = Matrix::<R, ROW, COL>::default();
+        for r in 0..ROW {
+            m.elements[r] = self.elements[r].zip_map(other.elements[r], &mut f);
+        }
+        m
+    }
+
+    pub fn fold<A>(self, init: A, mut f: impl FnMut(A, T) -> A) -> A {
+        self.elements
+            .into_iter()
+            .fold(init, |acc, row| row.fold(acc, &mut f))
+    }
+
+    pub fn for_each(self, mut f: impl FnMut(T)) {
+        self.elements
+            .into_iter()
+            .for_each(|row| row.for_each(&mut f));
+    }
+
+    pub fn hadamard(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+
+    pub fn component_div(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |a, b| a / b)
+    }
+
+    pub fn kronecker<const R: usize, const C: usize, const KR: usize, const KC: usize>(
+        self,
+        other: Matrix<T, R, C>,
+    ) -> Matrix<T, KR, KC> {
+        const {
+            assert!(KR == ROW * R && KC == COL * C);
+        }
+        let mut m = Matrix::<T, KR, KC>::default();
+        for r in 0..KR {
+            for c in 0..KC {
+                m.elements[r][c] = self.elements[r / R][c / C] * other.elements[r % R][c % C];
+            }
+        }
+        m
+    }
 }
 
 impl<T: MatrixElement + Float> Matrix<T, 2, 2> {
-    pub fn rotate(theta: T) -> Self {
-        let c = theta.cos();
-        let s = theta.sin();
+    pub fn rotate(theta: impl Into<Rad<T>>) -> Self {
+        let (s, c) = theta.into().sin_cos();
         [[c, s], [-s, c]].into()
     }
 }
@@ -229,6 +305,185 @@ impl<T: MatrixElement + One, const N: usize> SquareMatrix for Matrix<T, N, N> {
     }
 }
 
+impl<T: MatrixElement + Float, const N: usize> Matrix<T, N, N> {
+    fn pivot_epsilon(&self) -> T {
+        self.fold(T::zero(), |max, e| max.max(e.abs())) * T::epsilon()
+    }
+
+    // The row at or below `col` with the largest magnitude in `col`, or `None`
+    // if one of them is NaN.
+    fn pivot_row(&self, col: usize) -> Option<usize> {
+        let mut pivot = col;
+        for r in col..N {
+            let e = self[r][col].abs();
+            if e.is_nan() {
+                return None;
+            }
+            if e > self[pivot][col].abs() {
+                pivot = r;
+            }
+        }
+        Some(pivot)
+    }
+
+    pub fn determinant(&self) -> T {
+        let epsilon = self.pivot_epsilon();
+        let mut m = *self;
+        let mut det = T::one();
+        for i in 0..N {
+            let pivot = match m.pivot_row(i) {
+                Some(pivot) => pivot,
+                None => return T::nan(),
+            };
+            if m[pivot][i].abs() <= epsilon {
+                return T::zero();
+            }
+            if pivot != i {
+                m.elements.swap(pivot, i);
+                det = -det;
+            }
+            det *= m[i][i];
+            for r in (i + 1)..N {
+                let factor = m[r][i] / m[i][i];
+                let row = m[i];
+                m[r] -= row * factor;
+            }
+        }
+        det
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let epsilon = self.pivot_epsilon();
+        let mut m = *self;
+        let mut inv = Self::identity();
+        for i in 0..N {
+            let pivot = m.pivot_row(i)?;
+            if m[pivot][i].abs() <= epsilon {
+                return None;
+            }
+            m.elements.swap(pivot, i);
+            inv.elements.swap(pivot, i);
+            let scale = T::one() / m[i][i];
+            m[i] *= scale;
+            inv[i] *= scale;
+            for r in 0..N {
+                if r != i {
+                    let factor = m[r][i];
+                    let (m_row, inv_row) = (m[i], inv[i]);
+                    m[r] -= m_row * factor;
+                    inv[r] -= inv_row * factor;
+                }
+            }
+        }
+        Some(inv)
+    }
+}
+
+impl<T, const ROW: usize, const COL: usize> Matrix<T, ROW, COL>
+where
+    T: MatrixElement + CheckedSub,
+{
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let mut m = Self::zero();
+        for r in 0..ROW {
+            for c in 0..COL {
+                m.elements[r][c] = self.elements[r][c].checked_sub(&rhs.elements[r][c])?;
+            }
+        }
+        Some(m)
+    }
+}
+
+impl<T, const ROW: usize, const COL: usize> Matrix<T, ROW, COL>
+where
+    T: MatrixElement + CheckedAdd + CheckedMul,
+{
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut m = Self::zero();
+        for r in 0..ROW {
+            for c in 0..COL {
+                m.elements[r][c] = self.elements[r][c].checked_add(&rhs.elements[r][c])?;
+            }
+        }
+        Some(m)
+    }
+
+    pub fn checked_mul<const N: usize>(self, rhs: Matrix<T, COL, N>) -> Option<Matrix<T, ROW, N>> {
+        let mut m = Matrix::<T, ROW, N>::default();
+        for r in 0..ROW {
+            for c in 0..N {
+                let mut sum = T::zero();
+                for i in 0..COL {
+                    let product = self.elements[r][i].checked_mul(&rhs.elements[i][c])?;
+                    sum = sum.checked_add(&product)?;
+                }
+                m.elements[r][c] = sum;
+            }
+        }
+        Some(m)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: MatrixElement + CheckedAdd + CheckedMul,
+{
+    pub fn checked_pow(self, mut exp: u64) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+// Every element is reduced into `0..modulus` before it is multiplied, so the
+// products stay exact as long as `modulus * modulus` fits in `T`. Both
+// functions panic if `modulus` is zero.
+impl<T: MatrixElement + Integer, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
+    pub fn mul_mod<const N: usize>(self, rhs: Matrix<T, COL, N>, modulus: T) -> Matrix<T, ROW, N> {
+        assert!(!modulus.is_zero(), "modulus must not be zero");
+        let mut m = Matrix::<T, ROW, N>::default();
+        for r in 0..ROW {
+            for c in 0..N {
+                let mut sum = T::zero();
+                for i in 0..COL {
+                    let a = self.elements[r][i].mod_floor(&modulus);
+                    let b = rhs.elements[i][c].mod_floor(&modulus);
+                    sum = (sum + a * b).mod_floor(&modulus);
+                }
+                m.elements[r][c] = sum;
+            }
+        }
+        m
+    }
+}
+
+impl<T: MatrixElement + Integer, const N: usize> Matrix<T, N, N> {
+    pub fn pow_mod(self, mut exp: u64, modulus: T) -> Self {
+        assert!(!modulus.is_zero(), "modulus must not be zero");
+        let mut base = self.map(|e| e.mod_floor(&modulus));
+        let mut result = Self::identity().map(|e| e.mod_floor(&modulus));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_mod(base, modulus);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul_mod(base, modulus);
+            }
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod ops_test {
     use super::*;
@@ -387,4 +642,230 @@ mod test {
         let m: Matrix3<i32> = [[1, 0, 0], [0, 1, 0], [0, 0, 1]].into();
         assert_eq!(m, Matrix::identity());
     }
+
+    #[test]
+    fn transpose() {
+        let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        assert_eq!(m.transpose(), [[1, 4], [2, 5], [3, 6]].to_matrix());
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn determinant() {
+        let m: Matrix2<f64> = [[1.0, 2.0], [3.0, 4.0]].into();
+        assert!((m.determinant() - -2.0).abs() < 1.0e-12);
+
+        let m: Matrix3<f64> = [[0.0, 2.0, 1.0], [3.0, -1.0, 2.0], [1.0, 1.0, 4.0]].into();
+        assert!((m.determinant() - -16.0).abs() < 1.0e-12);
+
+        let m: Matrix3<f64> = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]].into();
+        assert_eq!(m.determinant(), 0.0);
+        assert_eq!(Matrix::<f64, 4, 4>::identity().determinant(), 1.0);
+
+        let m: Matrix2<f64> = [[f64::NAN, 0.0], [0.0, 1.0]].into();
+        assert!(m.determinant().is_nan());
+        let m: Matrix2<f64> = [[1.0, f64::NAN], [0.0, 1.0]].into();
+        assert!(m.determinant().is_nan());
+    }
+
+    #[test]
+    fn inverse() {
+        let m: Matrix3<f64> = [[0.0, 2.0, 1.0], [3.0, -1.0, 2.0], [1.0, 1.0, 4.0]].into();
+        let inv = m.inverse().unwrap();
+        let product = m * inv;
+        let identity = Matrix3::<f64>::identity();
+        for r in 0..3 {
+            for c in 0..3 {
+                assert!((product[r][c] - identity[r][c]).abs() < 1.0e-12);
+            }
+        }
+
+        let m: Matrix2<f64> = [[2.0, 0.0], [1.0, 4.0]].into();
+        assert_eq!(m.inverse(), Some([[0.5, 0.0], [-0.125, 0.25]].to_matrix()));
+
+        let m: Matrix3<f64> = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]].into();
+        assert_eq!(m.inverse(), None);
+
+        let m: Matrix2<f64> = [[1.0, f64::NAN], [0.0, 1.0]].into();
+        assert_eq!(m.inverse(), None);
+        let m: Matrix2<f64> = [[f64::NAN, 0.0], [0.0, 1.0]].into();
+        assert_eq!(m.inverse(), None);
+    }
+
+    #[test]
+    fn map() {
+        let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        assert_eq!(m.map(|e| e * e), [[1, 4, 9], [16, 25, 36]].to_matrix());
+        assert_eq!(
+            m.map(|e| e as f64 * 0.5),
+            [[0.5, 1.0, 1.5], [2.0, 2.5, 3.0]].to_matrix()
+        );
+    }
+
+    #[test]
+    fn zip_map() {
+        let m1: Matrix2<i32> = [[1, 2], [3, 4]].into();
+        let m2: Matrix2<i32> = [[5, 6], [7, 8]].into();
+        assert_eq!(
+            m1.zip_map(m2, |a, b| a * 10 + b),
+            [[15, 26], [37, 48]].to_matrix()
+        );
+    }
+
+    #[test]
+    fn fold() {
+        let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        assert_eq!(m.fold(0, |acc, e| acc + e), 21);
+        assert_eq!(m.fold(0, |acc, e| acc.max(e)), 6);
+    }
+
+    #[test]
+    fn for_each() {
+        let m: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        let mut visited = Vec::new();
+        m.for_each(|e| visited.push(e));
+        assert_eq!(visited, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn hadamard() {
+        let m1: Matrix<i32, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        let m2: Matrix<i32, 2, 3> = [[2, 3, 4], [5, 6, 7]].into();
+        assert_eq!(m1.hadamard(m2), [[2, 6, 12], [20, 30, 42]].to_matrix());
+    }
+
+    #[test]
+    fn component_div() {
+        let m1: Matrix2<f64> = [[1.0, 2.0], [9.0, 5.0]].into();
+        let m2: Matrix2<f64> = [[2.0, 4.0], [3.0, 0.5]].into();
+        assert_eq!(m1.component_div(m2), [[0.5, 0.5], [3.0, 10.0]].to_matrix());
+    }
+
+    #[test]
+    fn kronecker() {
+        let m1: Matrix2<i32> = [[1, 2], [3, 4]].into();
+        let m2: Matrix2<i32> = [[0, 5], [6, 7]].into();
+        let k: Matrix<i32, 4, 4> = m1.kronecker(m2);
+        assert_eq!(
+            k,
+            [
+                [0, 5, 0, 10],
+                [6, 7, 12, 14],
+                [0, 15, 0, 20],
+                [18, 21, 24, 28]
+            ]
+            .to_matrix()
+        );
+
+        let m1: Matrix<i32, 1, 2> = [[1, 2]].into();
+        let m2: Matrix<i32, 2, 1> = [[3], [4]].into();
+        let k: Matrix<i32, 2, 2> = m1.kronecker(m2);
+        assert_eq!(k, [[3, 6], [4, 8]].to_matrix());
+    }
+
+    #[test]
+    fn pow() {
+        let m: Matrix2<i64> = [[1, 1], [1, 0]].into();
+        assert_eq!(m.pow(0), Matrix::identity());
+        assert_eq!(m.pow(1), m);
+        assert_eq!(m.pow(10), [[89, 55], [55, 34]].to_matrix());
+        assert_eq!(m.pow(90)[0][1], 2880067194370816120);
+
+        let m: Matrix2<f64> = [[2.0, 0.0], [0.0, 0.5]].into();
+        assert_eq!(m.pow(3), [[8.0, 0.0], [0.0, 0.125]].to_matrix());
+    }
+
+    #[test]
+    fn pow_path_count() {
+        // triangle graph with a pendant vertex: 0-1, 1-2, 2-0, 2-3
+        let adjacency: Matrix<i64, 4, 4> =
+            [[0, 1, 1, 0], [1, 0, 1, 0], [1, 1, 0, 1], [0, 0, 1, 0]].into();
+        let walks = adjacency.pow(3);
+        assert_eq!(walks[0][0], 2);
+        assert_eq!(walks[2][2], 2);
+        assert_eq!(walks[0][3], 1);
+        assert_eq!(walks, adjacency * adjacency * adjacency);
+    }
+
+    #[test]
+    fn checked_add() {
+        let m1: Matrix2<i8> = [[1, 2], [3, 4]].into();
+        let m2: Matrix2<i8> = [[5, 6], [7, 8]].into();
+        assert_eq!(m1.checked_add(m2), Some([[6, 8], [10, 12]].to_matrix()));
+
+        let m2: Matrix2<i8> = [[5, 6], [7, i8::MAX]].into();
+        assert_eq!(m1.checked_add(m2), None);
+    }
+
+    #[test]
+    fn checked_sub() {
+        let m1: Matrix2<u8> = [[5, 6], [7, 8]].into();
+        let m2: Matrix2<u8> = [[1, 2], [3, 4]].into();
+        assert_eq!(m1.checked_sub(m2), Some([[4, 4], [4, 4]].to_matrix()));
+        assert_eq!(m2.checked_sub(m1), None);
+    }
+
+    #[test]
+    fn checked_mul() {
+        let m1: Matrix<i64, 2, 3> = [[1, 2, 3], [4, 5, 6]].into();
+        let m2: Matrix<i64, 3, 1> = [[1], [2], [3]].into();
+        assert_eq!(m1.checked_mul(m2), Some([[14], [32]].to_matrix()));
+
+        let m: Matrix2<i64> = [[i64::MAX / 2 + 1, 0], [0, 1]].into();
+        assert_eq!(m.checked_mul(m), None);
+
+        let m: Matrix2<i64> = [[i64::MAX / 2, 0], [0, 1]].into();
+        let two: Matrix2<i64> = [[1, 1], [1, 1]].into();
+        assert_eq!(two.checked_mul(m), Some(two * m));
+        let m: Matrix2<i64> = [[i64::MAX / 2 + 1, 0], [i64::MAX / 2 + 1, 0]].into();
+        assert_eq!(two.checked_mul(m), None);
+    }
+
+    #[test]
+    fn checked_pow() {
+        let m: Matrix2<i64> = [[1, 1], [1, 0]].into();
+        assert_eq!(
+            m.checked_pow(91).map(|m| m[0][0]),
+            Some(7540113804746346429)
+        );
+        assert_eq!(m.checked_pow(92), None);
+        assert_eq!(m.checked_pow(0), Some(Matrix::identity()));
+    }
+
+    #[test]
+    fn mul_mod() {
+        let m1: Matrix2<i64> = [[1, 2], [3, 4]].into();
+        let m2: Matrix2<i64> = [[5, 6], [7, 8]].into();
+        assert_eq!(m1.mul_mod(m2, 10), [[9, 2], [3, 0]].to_matrix());
+
+        let m1: Matrix2<i64> = [[-1, 2], [3, -4]].into();
+        assert_eq!(m1.mul_mod(m2, 7), [[2, 3], [1, 0]].to_matrix());
+    }
+
+    #[test]
+    fn pow_mod() {
+        const MODULUS: i64 = 1_000_000_007;
+        let m: Matrix2<i64> = [[1, 1], [1, 0]].into();
+        assert_eq!(m.pow_mod(1000, MODULUS)[0][1], 517691607);
+        assert_eq!(m.pow_mod(10, 7), m.pow(10).map(|e| e % 7));
+        assert_eq!(m.pow_mod(0, 7), Matrix::identity());
+        assert_eq!(m.pow_mod(0, 1), Matrix::zero());
+
+        let m: Matrix2<i64> = [[-1, 0], [0, -1]].into();
+        assert_eq!(m.pow_mod(3, 5), [[4, 0], [0, 4]].to_matrix());
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must not be zero")]
+    fn mul_mod_zero_modulus() {
+        let m: Matrix2<i64> = [[1, 2], [3, 4]].into();
+        m.mul_mod(m, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must not be zero")]
+    fn pow_mod_zero_modulus() {
+        let m: Matrix2<i64> = [[1, 2], [3, 4]].into();
+        m.pow_mod(0, 0);
+    }
 }
@@ -0,0 +1,119 @@
+use super::*;
+use num::traits::AsPrimitive;
+use num::{Bounded, NumCast, ToPrimitive};
+
+// Element conversions follow `Vector::cast`, `Vector::try_cast` and
+// `Vector::saturating_cast`.
+impl<T: MatrixElement, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
+    pub fn cast<U>(self) -> Matrix<U, ROW, COL>
+    where
+        T: AsPrimitive<U>,
+        U: MatrixElement + 'static,
+    {
+        Matrix {
+            elements: self.elements.map(|row| row.cast()),
+        }
+    }
+
+    pub fn try_cast<U>(self) -> Option<Matrix<U, ROW, COL>>
+    where
+        T: ToPrimitive,
+        U: MatrixElement + NumCast,
+    {
+        let mut m = Matrix::<U, ROW, COL>::default();
+        for r in 0..ROW {
+            m.elements[r] = self.elements[r].try_cast()?;
+        }
+        Some(m)
+    }
+
+    pub fn saturating_cast<U>(self) -> Matrix<U, ROW, COL>
+    where
+        T: ToPrimitive + PartialOrd,
+        U: MatrixElement + NumCast + Bounded,
+    {
+        Matrix {
+            elements: self.elements.map(|row| row.saturating_cast()),
+        }
+    }
+}
+
+macro_rules! impl_from_widening {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl<const ROW: usize, const COL: usize> From<Matrix<$from, ROW, COL>>
+                for Matrix<$to, ROW, COL>
+            {
+                fn from(m: Matrix<$from, ROW, COL>) -> Self {
+                    Matrix {
+                        elements: m.elements.map(Vector::from),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_widening!(i8 => i16, i32, i64, i128, f32, f64);
+impl_from_widening!(i16 => i32, i64, i128, f32, f64);
+impl_from_widening!(i32 => i64, i128, f64);
+impl_from_widening!(i64 => i128);
+impl_from_widening!(u8 => u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+impl_from_widening!(u16 => u32, u64, u128, i32, i64, i128, f32, f64);
+impl_from_widening!(u32 => u64, u128, i64, i128, f64);
+impl_from_widening!(u64 => u128, i128);
+impl_from_widening!(f32 => f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cast() {
+        let m = [[1.5_f64, -2.5], [3.9, 1.0e10]].to_matrix();
+        assert_eq!(m.cast::<i32>(), [[1, -2], [3, i32::MAX]].to_matrix());
+        assert_eq!(
+            m.cast::<f32>(),
+            [[1.5_f32, -2.5], [3.9, 1.0e10]].to_matrix()
+        );
+    }
+
+    #[test]
+    fn try_cast() {
+        let m = [[1, 2], [3, 255]].to_matrix();
+        assert_eq!(m.try_cast::<u8>(), Some([[1_u8, 2], [3, 255]].to_matrix()));
+        assert_eq!(m.try_cast::<i8>(), None);
+
+        let m = [[1.0_f64, 1.0e300], [0.5, f64::INFINITY]].to_matrix();
+        assert_eq!(m.try_cast::<f32>(), None);
+        let m = [[1.0_f64, -1.0], [0.5, f64::INFINITY]].to_matrix();
+        assert_eq!(
+            m.try_cast::<f32>(),
+            Some([[1.0_f32, -1.0], [0.5, f32::INFINITY]].to_matrix())
+        );
+    }
+
+    #[test]
+    fn saturating_cast() {
+        let m = [[-300, 2], [3, 300]].to_matrix();
+        assert_eq!(
+            m.saturating_cast::<i8>(),
+            [[-128_i8, 2], [3, 127]].to_matrix()
+        );
+        assert_eq!(m.saturating_cast::<u8>(), [[0_u8, 2], [3, 255]].to_matrix());
+
+        let m = [[-1.0e300_f64, 1.0e300], [0.5, f64::NEG_INFINITY]].to_matrix();
+        assert_eq!(
+            m.saturating_cast::<f32>(),
+            [[f32::MIN, f32::MAX], [0.5, f32::NEG_INFINITY]].to_matrix()
+        );
+    }
+
+    #[test]
+    fn from_widening() {
+        let m: Matrix<f64, 2, 2> = Matrix::<f32, 2, 2>::identity().into();
+        assert_eq!(m, Matrix::identity());
+        let m: Matrix<i64, 1, 2> = [[-1_i32, 2]].to_matrix().into();
+        assert_eq!(m, [[-1_i64, 2]].to_matrix());
+    }
+}
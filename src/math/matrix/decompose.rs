@@ -0,0 +1,180 @@
+use super::*;
+use crate::math::quaternion::Quaternion;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecomposeError {
+    Projection,
+    Shear,
+    Singular,
+}
+
+impl Display for DecomposeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DecomposeError::Projection => "matrix has a projective part",
+            DecomposeError::Shear => "matrix has a shear",
+            DecomposeError::Singular => "matrix has a zero scale",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for DecomposeError {}
+
+// `(translation, rotation, scale)`
+pub type Trs<T> = (Vector<T, 3>, Quaternion<T>, Vector<T, 3>);
+
+// Matrices are composed and decomposed as `scaling * rotation * translation`,
+// which applies the scale first in the row-vector convention. A reflection is
+// reported as a negative x scale.
+impl<T: MatrixElement + Float> Matrix<T, 4, 4> {
+    pub fn from_trs(
+        translation: impl VectorLike<T, 3>,
+        rotation: Quaternion<T>,
+        scale: impl VectorLike<T, 3>,
+    ) -> Self {
+        let mut m = rotation.to_matrix4();
+        for r in 0..3 {
+            m[r] *= *scale.get(r);
+            m[3][r] = *translation.get(r);
+        }
+        m
+    }
+
+    pub fn decompose(&self) -> Option<Trs<T>> {
+        self.try_decompose().ok()
+    }
+
+    // Shear and projection are detected with a tolerance of the square root
+    // of the machine epsilon, relative to the scale of the matrix.
+    pub fn try_decompose(&self) -> Result<Trs<T>, DecomposeError> {
+        let tolerance = T::epsilon().sqrt();
+        let magnitude = self.fold(T::zero(), |max, e| max.max(e.abs()));
+        if magnitude.is_zero() {
+            return Err(DecomposeError::Singular);
+        }
+        for r in 0..3 {
+            if self[r][3].abs() > tolerance * magnitude {
+                return Err(DecomposeError::Projection);
+            }
+        }
+        if (self[3][3] - T::one()).abs() > tolerance {
+            return Err(DecomposeError::Projection);
+        }
+
+        let translation = self[3].xyz();
+        let mut rows = [self[0].xyz(), self[1].xyz(), self[2].xyz()];
+        let mut scale = Vector::<T, 3>::zero();
+        for i in 0..3 {
+            scale[i] = rows[i].length();
+            if scale[i] <= tolerance * magnitude {
+                return Err(DecomposeError::Singular);
+            }
+            rows[i] /= scale[i];
+        }
+        for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+            if rows[a].dot(rows[b]).abs() > tolerance {
+                return Err(DecomposeError::Shear);
+            }
+        }
+        if rows[0].dot(rows[1].cross(rows[2])) < T::zero() {
+            scale[0] = -scale[0];
+            rows[0] = -rows[0];
+        }
+        let rotation = Quaternion::from_matrix3(rows.into());
+        Ok((translation, rotation, scale))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    #[test]
+    fn from_trs() {
+        let t = Vector3::new(1.0, 2.0, 3.0);
+        let r = Quaternion::from_axis_angle([0.0, 0.0, 1.0], 0.5);
+        let s = Vector3::new(2.0, 3.0, 4.0);
+        let expected = Matrix4::scaling(s)
+            * Matrix4::rotation_axis_angle([0.0, 0.0, 1.0], 0.5)
+            * Matrix4::translation(t);
+        assert_approx_eq(Matrix4::from_trs(t, r, s), expected, 1.0e-10);
+        assert_eq!(
+            Matrix4::from_trs([0.0; 3], Quaternion::identity(), [1.0; 3]),
+            Matrix4::identity()
+        );
+    }
+
+    #[test]
+    fn decompose() {
+        let t = Vector3::new(1.0, -2.0, 3.0);
+        let r = Quaternion::from_axis_angle([1.0, 2.0, -0.5], 2.1);
+        let s = Vector3::new(0.5, 3.0, 4.0);
+        let (t2, r2, s2) = Matrix4::from_trs(t, r, s).decompose().unwrap();
+        assert_approx_eq(t2, t, 1.0e-10);
+        assert_approx_eq(s2, s, 1.0e-10);
+        assert!((r2.dot(r).abs() - 1.0).abs() < 1.0e-10);
+
+        let (t, r, s) = Matrix4::identity().decompose().unwrap();
+        assert_approx_eq(t, Vector3::zero(), 1.0e-10);
+        assert_approx_eq(s, Vector3::one(), 1.0e-10);
+        assert!((r.w.abs() - 1.0).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn decompose_negative_scale() {
+        let t = Vector3::new(1.0, -2.0, 3.0);
+        let r = Quaternion::from_axis_angle([1.0, 2.0, -0.5], 0.7);
+        for s in [
+            Vector3::new(-2.0, 3.0, 4.0),
+            Vector3::new(2.0, -3.0, 4.0),
+            Vector3::new(-2.0, -3.0, -4.0),
+        ] {
+            let m = Matrix4::from_trs(t, r, s);
+            let (t2, r2, s2) = m.decompose().unwrap();
+            assert!(s2.x() < 0.0);
+            assert!(s2.y() > 0.0 && s2.z() > 0.0);
+            assert_approx_eq(t2, t, 1.0e-10);
+            assert_approx_eq(Matrix4::from_trs(t2, r2, s2), m, 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn decompose_shear() {
+        let m =
+            Matrix4::shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0) * Matrix4::translation([1.0, 2.0, 3.0]);
+        assert_eq!(m.try_decompose(), Err(DecomposeError::Shear));
+        assert_eq!(m.decompose(), None);
+
+        let m = Matrix4::rotation_z(0.3) * Matrix4::scaling([1.0, 2.0, 1.0]);
+        assert_eq!(m.try_decompose(), Err(DecomposeError::Shear));
+    }
+
+    #[test]
+    fn decompose_projection() {
+        let m = Matrix4::perspective_fov_rh(1.0, 1.0, 0.1, 100.0, DepthRange::ZeroToOne);
+        assert_eq!(m.try_decompose(), Err(DecomposeError::Projection));
+
+        let mut m = Matrix4::identity();
+        m[3][3] = 2.0;
+        assert_eq!(m.try_decompose(), Err(DecomposeError::Projection));
+    }
+
+    #[test]
+    fn decompose_singular() {
+        let m = Matrix4::scaling([1.0, 0.0, 1.0]);
+        assert_eq!(m.try_decompose(), Err(DecomposeError::Singular));
+        assert_eq!(
+            Matrix4::zero().try_decompose(),
+            Err(DecomposeError::Singular)
+        );
+        assert_eq!(
+            DecomposeError::Singular.to_string(),
+            "matrix has a zero scale"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+impl<T: MatrixElement, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
+    pub fn iter_rows(&self) -> std::slice::Iter<'_, Vector<T, COL>> {
+        self.elements.iter()
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = Vector<T, ROW>> + '_ {
+        (0..COL).map(|c| self.col(c))
+    }
+}
+
+impl<T: MatrixElement, const ROW: usize, const COL: usize> std::iter::Sum for Matrix<T, ROW, COL> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, m| acc + m)
+    }
+}
+
+impl<'a, T: MatrixElement, const ROW: usize, const COL: usize> std::iter::Sum<&'a Self>
+    for Matrix<T, ROW, COL>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, m| acc + *m)
+    }
+}
+
+// Multiplies in iteration order, so the first matrix is applied first.
+impl<T: MatrixElement, const N: usize> std::iter::Product for Matrix<T, N, N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, m| acc * m)
+    }
+}
+
+impl<'a, T: MatrixElement, const N: usize> std::iter::Product<&'a Self> for Matrix<T, N, N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, m| acc * *m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Matrix2 = Matrix<i32, 2, 2>;
+
+    #[test]
+    fn iter_rows_cols() {
+        let m = [[1, 2, 3], [4, 5, 6]].to_matrix();
+        let rows: Vec<_> = m.iter_rows().copied().collect();
+        assert_eq!(
+            rows,
+            vec![
+                Vector::<i32, 3>::new(1, 2, 3),
+                Vector::<i32, 3>::new(4, 5, 6)
+            ]
+        );
+        let cols: Vec<_> = m.iter_cols().collect();
+        assert_eq!(
+            cols,
+            vec![
+                Vector::<i32, 2>::new(1, 4),
+                Vector::<i32, 2>::new(2, 5),
+                Vector::<i32, 2>::new(3, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let ms = [Matrix2::identity(), [[1, 2], [3, 4]].to_matrix()];
+        assert_eq!(ms.iter().sum::<Matrix2>(), [[2, 2], [3, 5]].to_matrix());
+        assert_eq!(
+            ms.into_iter().sum::<Matrix2>(),
+            [[2, 2], [3, 5]].to_matrix()
+        );
+        assert_eq!(
+            std::iter::empty::<Matrix2>().sum::<Matrix2>(),
+            Matrix2::zero()
+        );
+    }
+
+    #[test]
+    fn product() {
+        let a: Matrix2 = [[1, 2], [3, 4]].to_matrix();
+        let b: Matrix2 = [[0, 1], [1, 0]].to_matrix();
+        assert_eq!([a, b].iter().product::<Matrix2>(), a * b);
+        assert_eq!([a, b].into_iter().product::<Matrix2>(), a * b);
+        assert_ne!(a * b, b * a);
+        assert_eq!(
+            std::iter::empty::<Matrix2>().product::<Matrix2>(),
+            Matrix2::identity()
+        );
+    }
+}
@@ -0,0 +1,824 @@
+use super::*;
+
+// Projection matrices map view space to clip space as `v * M`. Right-handed
+// variants look down `-Z`, left-handed variants look down `+Z`, and both put
+// `+Y` up. `DepthRange` selects the NDC depth range of the target API:
+// OpenGL uses `NegativeOneToOne`, while Vulkan, D3D and Metal use `ZeroToOne`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    NegativeOneToOne,
+    ZeroToOne,
+}
+
+impl DepthRange {
+    fn near_far<T: Float>(self, reverse_z: bool) -> (T, T) {
+        let (near, far) = match self {
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+        };
+        if reverse_z {
+            (far, near)
+        } else {
+            (near, far)
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Handedness {
+    Left,
+    Right,
+}
+
+impl Handedness {
+    fn sign<T: Float>(self) -> T {
+        match self {
+            Handedness::Left => T::one(),
+            Handedness::Right => -T::one(),
+        }
+    }
+}
+
+struct ProjectionScale<T> {
+    x_scale: T,
+    y_scale: T,
+    x_offset: T,
+    y_offset: T,
+}
+
+impl<T: Float> ProjectionScale<T> {
+    fn fov(fovy: Rad<T>, aspect: T) -> Self {
+        let two = T::one() + T::one();
+        let y_scale = T::one() / (fovy / two).tan();
+        Self {
+            x_scale: y_scale / aspect,
+            y_scale,
+            x_offset: T::zero(),
+            y_offset: T::zero(),
+        }
+    }
+
+    fn off_center(left: T, right: T, bottom: T, top: T, near: T) -> Self {
+        let two = T::one() + T::one();
+        Self {
+            x_scale: two * near / (right - left),
+            y_scale: two * near / (top - bottom),
+            x_offset: (right + left) / (right - left),
+            y_offset: (top + bottom) / (top - bottom),
+        }
+    }
+}
+
+impl<T: MatrixElement + Float> Matrix<T, 4, 4> {
+    // NDC depth is `a + b / d` for a view depth `d`; `far: None` places the
+    // far plane at infinity.
+    fn perspective(
+        scale: ProjectionScale<T>,
+        near: T,
+        far: Option<T>,
+        handedness: Handedness,
+        depth_range: DepthRange,
+        reverse_z: bool,
+    ) -> Self {
+        let (z_near, z_far) = depth_range.near_far::<T>(reverse_z);
+        let (a, b) = match far {
+            Some(far) => (
+                (z_far * far - z_near * near) / (far - near),
+                (z_near - z_far) * near * far / (far - near),
+            ),
+            None => (z_far, (z_near - z_far) * near),
+        };
+        let s = handedness.sign::<T>();
+        let o = T::zero();
+        [
+            [scale.x_scale, o, o, o],
+            [o, scale.y_scale, o, o],
+            [-scale.x_offset * s, -scale.y_offset * s, a * s, s],
+            [o, o, b, o],
+        ]
+        .into()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn orthographic(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+        depth_range: DepthRange,
+        reverse_z: bool,
+    ) -> Self {
+        let two = T::one() + T::one();
+        let (z_near, z_far) = depth_range.near_far::<T>(reverse_z);
+        let a = (z_far - z_near) / (far - near);
+        let b = z_near - a * near;
+        let s = handedness.sign::<T>();
+        let (o, l) = (T::zero(), T::one());
+        [
+            [two / (right - left), o, o, o],
+            [o, two / (top - bottom), o, o],
+            [o, o, a * s, o],
+            [
+                -(right + left) / (right - left),
+                -(top + bottom) / (top - bottom),
+                b,
+                l,
+            ],
+        ]
+        .into()
+    }
+
+    pub fn perspective_fov_rh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(
+            scale,
+            near,
+            Some(far),
+            Handedness::Right,
+            depth_range,
+            false,
+        )
+    }
+
+    pub fn perspective_fov_lh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, Some(far), Handedness::Left, depth_range, false)
+    }
+
+    pub fn perspective_fov_reverse_z_rh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, Some(far), Handedness::Right, depth_range, true)
+    }
+
+    pub fn perspective_fov_reverse_z_lh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, Some(far), Handedness::Left, depth_range, true)
+    }
+
+    pub fn perspective_fov_infinite_rh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, None, Handedness::Right, depth_range, false)
+    }
+
+    pub fn perspective_fov_infinite_lh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, None, Handedness::Left, depth_range, false)
+    }
+
+    pub fn perspective_fov_infinite_reverse_z_rh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, None, Handedness::Right, depth_range, true)
+    }
+
+    pub fn perspective_fov_infinite_reverse_z_lh(
+        fovy: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::fov(fovy.into(), aspect);
+        Self::perspective(scale, near, None, Handedness::Left, depth_range, true)
+    }
+
+    pub fn frustum_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(
+            scale,
+            near,
+            Some(far),
+            Handedness::Right,
+            depth_range,
+            false,
+        )
+    }
+
+    pub fn frustum_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, Some(far), Handedness::Left, depth_range, false)
+    }
+
+    pub fn frustum_reverse_z_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, Some(far), Handedness::Right, depth_range, true)
+    }
+
+    pub fn frustum_reverse_z_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, Some(far), Handedness::Left, depth_range, true)
+    }
+
+    pub fn frustum_infinite_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, None, Handedness::Right, depth_range, false)
+    }
+
+    pub fn frustum_infinite_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, None, Handedness::Left, depth_range, false)
+    }
+
+    pub fn frustum_infinite_reverse_z_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, None, Handedness::Right, depth_range, true)
+    }
+
+    pub fn frustum_infinite_reverse_z_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        let scale = ProjectionScale::off_center(left, right, bottom, top, near);
+        Self::perspective(scale, near, None, Handedness::Left, depth_range, true)
+    }
+
+    pub fn orthographic_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::orthographic(
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            Handedness::Right,
+            depth_range,
+            false,
+        )
+    }
+
+    pub fn orthographic_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::orthographic(
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            Handedness::Left,
+            depth_range,
+            false,
+        )
+    }
+
+    pub fn orthographic_reverse_z_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::orthographic(
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            Handedness::Right,
+            depth_range,
+            true,
+        )
+    }
+
+    pub fn orthographic_reverse_z_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth_range: DepthRange,
+    ) -> Self {
+        Self::orthographic(
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            Handedness::Left,
+            depth_range,
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    const NEAR: f64 = 0.5;
+    const FAR: f64 = 100.0;
+
+    fn clip_w(m: Matrix4, p: Vector3) -> f64 {
+        (Vector::<f64, 4>::new(p.x(), p.y(), p.z(), 1.0) * m).w()
+    }
+
+    #[test]
+    fn perspective_fov_rh_matches_opengl() {
+        // gluPerspective, transposed for the row-vector convention
+        let (fovy, aspect) = (1.0_f64, 1.5);
+        let f = 1.0 / (fovy / 2.0).tan();
+        let expected: Matrix4 = [
+            [f / aspect, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [0.0, 0.0, (FAR + NEAR) / (NEAR - FAR), -1.0],
+            [0.0, 0.0, 2.0 * FAR * NEAR / (NEAR - FAR), 0.0],
+        ]
+        .into();
+        let m = Matrix4::perspective_fov_rh(fovy, aspect, NEAR, FAR, DepthRange::NegativeOneToOne);
+        for r in 0..4 {
+            for c in 0..4 {
+                assert!((m[r][c] - expected[r][c]).abs() < 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn perspective_fov_rh() {
+        let m = Matrix4::perspective_fov_rh(FRAC_PI_2, 2.0, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -FAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([2.0 * NEAR, NEAR, -NEAR]),
+            Vector3::new(1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+        assert!(clip_w(m, Vector3::new(0.0, 0.0, -1.0)) > 0.0);
+
+        let m =
+            Matrix4::perspective_fov_rh(FRAC_PI_2, 2.0, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, -1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -FAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([-2.0 * FAR, -FAR, -FAR]),
+            Vector3::new(-1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+    }
+
+    #[test]
+    fn perspective_fov_lh() {
+        let m = Matrix4::perspective_fov_lh(FRAC_PI_2, 2.0, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, NEAR]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, FAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([2.0 * NEAR, NEAR, NEAR]),
+            Vector3::new(1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+        assert!(clip_w(m, Vector3::new(0.0, 0.0, 1.0)) > 0.0);
+
+        let m =
+            Matrix4::perspective_fov_lh(FRAC_PI_2, 2.0, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, NEAR]),
+            Vector3::new(0.0, 0.0, -1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, FAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+    }
+
+    #[test]
+    fn perspective_fov_reverse_z() {
+        let m =
+            Matrix4::perspective_fov_reverse_z_rh(FRAC_PI_2, 1.0, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -FAR]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([NEAR, NEAR, -NEAR]),
+            Vector3::new(1.0, 1.0, 1.0),
+            1.0e-9,
+        );
+
+        let m =
+            Matrix4::perspective_fov_reverse_z_lh(FRAC_PI_2, 1.0, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, NEAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, FAR]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+
+        let m = Matrix4::perspective_fov_reverse_z_rh(
+            FRAC_PI_2,
+            1.0,
+            NEAR,
+            FAR,
+            DepthRange::NegativeOneToOne,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -FAR]),
+            Vector3::new(0.0, 0.0, -1.0),
+            1.0e-9,
+        );
+    }
+
+    #[test]
+    fn perspective_fov_infinite() {
+        let m = Matrix4::perspective_fov_infinite_rh(FRAC_PI_2, 1.0, NEAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, -1.0e12]).z();
+        assert!(z < 1.0 && 1.0 - z < 1.0e-9);
+
+        let m = Matrix4::perspective_fov_infinite_lh(
+            FRAC_PI_2,
+            1.0,
+            NEAR,
+            DepthRange::NegativeOneToOne,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, NEAR]),
+            Vector3::new(0.0, 0.0, -1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, 1.0e12]).z();
+        assert!(z < 1.0 && 1.0 - z < 1.0e-9);
+    }
+
+    #[test]
+    fn perspective_fov_infinite_reverse_z() {
+        let m = Matrix4::perspective_fov_infinite_reverse_z_rh(
+            FRAC_PI_2,
+            1.0,
+            NEAR,
+            DepthRange::ZeroToOne,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, -NEAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, -1.0e12]).z();
+        assert!(z > 0.0 && z < 1.0e-9);
+
+        let m = Matrix4::perspective_fov_infinite_reverse_z_lh(
+            FRAC_PI_2,
+            1.0,
+            NEAR,
+            DepthRange::ZeroToOne,
+        );
+        assert_approx_eq(
+            m.transform_point([0.0, 0.0, NEAR]),
+            Vector3::new(0.0, 0.0, 1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, 1.0e12]).z();
+        assert!(z > 0.0 && z < 1.0e-9);
+    }
+
+    #[test]
+    fn frustum() {
+        let (l, r, b, t) = (-1.0, 3.0, -2.0, 1.0);
+        let m = Matrix4::frustum_rh(l, r, b, t, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([l, b, -NEAR]),
+            Vector3::new(-1.0, -1.0, -1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r, t, -NEAR]),
+            Vector3::new(1.0, 1.0, -1.0),
+            1.0e-9,
+        );
+        let s = FAR / NEAR;
+        assert_approx_eq(
+            m.transform_point([r * s, b * s, -FAR]),
+            Vector3::new(1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+
+        let m = Matrix4::frustum_lh(l, r, b, t, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, t, NEAR]),
+            Vector3::new(-1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r * s, b * s, FAR]),
+            Vector3::new(1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+    }
+
+    #[test]
+    fn frustum_reverse_z() {
+        let (l, r, b, t) = (-1.0, 3.0, -2.0, 1.0);
+        let s = FAR / NEAR;
+        let m = Matrix4::frustum_reverse_z_rh(l, r, b, t, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, b, -NEAR]),
+            Vector3::new(-1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r * s, t * s, -FAR]),
+            Vector3::new(1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+
+        let m = Matrix4::frustum_reverse_z_lh(l, r, b, t, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([r, b, NEAR]),
+            Vector3::new(1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([l * s, t * s, FAR]),
+            Vector3::new(-1.0, 1.0, -1.0),
+            1.0e-9,
+        );
+    }
+
+    #[test]
+    fn frustum_infinite() {
+        let (l, r, b, t) = (-1.0, 3.0, -2.0, 1.0);
+        let m = Matrix4::frustum_infinite_rh(l, r, b, t, NEAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, b, -NEAR]),
+            Vector3::new(-1.0, -1.0, 0.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, -1.0e12]).z();
+        assert!(z < 1.0 && 1.0 - z < 1.0e-9);
+
+        let m = Matrix4::frustum_infinite_lh(l, r, b, t, NEAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([r, t, NEAR]),
+            Vector3::new(1.0, 1.0, -1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, 1.0e12]).z();
+        assert!(z < 1.0 && 1.0 - z < 1.0e-9);
+    }
+
+    #[test]
+    fn frustum_infinite_reverse_z() {
+        let (l, r, b, t) = (-1.0, 3.0, -2.0, 1.0);
+        let m = Matrix4::frustum_infinite_reverse_z_rh(l, r, b, t, NEAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, t, -NEAR]),
+            Vector3::new(-1.0, 1.0, 1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, -1.0e12]).z();
+        assert!(z > 0.0 && z < 1.0e-9);
+
+        let m = Matrix4::frustum_infinite_reverse_z_lh(l, r, b, t, NEAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([r, b, NEAR]),
+            Vector3::new(1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+        let z = m.transform_point([0.0, 0.0, 1.0e12]).z();
+        assert!(z > 0.0 && z < 1.0e-9);
+    }
+
+    #[test]
+    fn frustum_symmetric_matches_fov() {
+        let t = NEAR * (FRAC_PI_2 / 2.0).tan();
+        let m1 = Matrix4::frustum_rh(-2.0 * t, 2.0 * t, -t, t, NEAR, FAR, DepthRange::ZeroToOne);
+        let m2 = Matrix4::perspective_fov_rh(FRAC_PI_2, 2.0, NEAR, FAR, DepthRange::ZeroToOne);
+        for r in 0..4 {
+            for c in 0..4 {
+                assert!((m1[r][c] - m2[r][c]).abs() < 1.0e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn orthographic() {
+        let (l, r, b, t) = (-4.0, 2.0, -1.0, 3.0);
+        let m = Matrix4::orthographic_rh(l, r, b, t, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([l, b, -NEAR]),
+            Vector3::new(-1.0, -1.0, -1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r, t, -FAR]),
+            Vector3::new(1.0, 1.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([-1.0, 1.0, -50.25]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+
+        let m = Matrix4::orthographic_lh(l, r, b, t, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, t, NEAR]),
+            Vector3::new(-1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r, b, FAR]),
+            Vector3::new(1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+        assert_eq!(clip_w(m, Vector3::new(1.0, 2.0, 3.0)), 1.0);
+    }
+    #[test]
+    fn orthographic_reverse_z() {
+        let (l, r, b, t) = (-4.0, 2.0, -1.0, 3.0);
+        let m = Matrix4::orthographic_reverse_z_rh(l, r, b, t, NEAR, FAR, DepthRange::ZeroToOne);
+        assert_approx_eq(
+            m.transform_point([l, b, -NEAR]),
+            Vector3::new(-1.0, -1.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r, t, -FAR]),
+            Vector3::new(1.0, 1.0, 0.0),
+            1.0e-9,
+        );
+
+        let m =
+            Matrix4::orthographic_reverse_z_lh(l, r, b, t, NEAR, FAR, DepthRange::NegativeOneToOne);
+        assert_approx_eq(
+            m.transform_point([l, t, NEAR]),
+            Vector3::new(-1.0, 1.0, 1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([r, b, FAR]),
+            Vector3::new(1.0, -1.0, -1.0),
+            1.0e-9,
+        );
+        assert_approx_eq(
+            m.transform_point([-1.0, 1.0, 50.25]),
+            Vector3::new(0.0, 0.0, 0.0),
+            1.0e-9,
+        );
+    }
+}
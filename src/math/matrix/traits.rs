@@ -20,6 +20,24 @@ where
 
 pub trait SquareMatrix {
     fn identity() -> Self;
+
+    fn pow(self, mut exp: u64) -> Self
+    where
+        Self: Sized + Copy + std::ops::Mul<Output = Self>,
+    {
+        let mut base = self;
+        let mut result = Self::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
 }
 
 #[cfg(test)]
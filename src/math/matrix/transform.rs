@@ -0,0 +1,476 @@
+use super::*;
+
+// All transforms follow the row-vector convention of `Vector * Matrix`:
+// a point is transformed as `v * M`, the translation lives in the last row,
+// and `A * B` applies `A` first and then `B`.
+
+impl<T: MatrixElement + Float> Matrix<T, 3, 3> {
+    pub fn scaling(v: impl VectorLike<T, 3>) -> Self {
+        let mut m = Self::identity();
+        for i in 0..3 {
+            m.elements[i][i] = *v.get(i);
+        }
+        m
+    }
+
+    pub fn uniform_scaling(s: T) -> Self {
+        Self::scaling([s, s, s])
+    }
+
+    pub fn rotation_x(theta: impl Into<Rad<T>>) -> Self {
+        let (s, c) = theta.into().sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        [[l, o, o], [o, c, s], [o, -s, c]].into()
+    }
+
+    pub fn rotation_y(theta: impl Into<Rad<T>>) -> Self {
+        let (s, c) = theta.into().sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        [[c, o, -s], [o, l, o], [s, o, c]].into()
+    }
+
+    pub fn rotation_z(theta: impl Into<Rad<T>>) -> Self {
+        let (s, c) = theta.into().sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        [[c, s, o], [-s, c, o], [o, o, l]].into()
+    }
+
+    pub fn rotation_axis_angle(axis: impl VectorLike<T, 3>, theta: impl Into<Rad<T>>) -> Self {
+        let axis = axis.to_vector().normalized();
+        let (x, y, z) = (axis.x(), axis.y(), axis.z());
+        let (s, c) = theta.into().sin_cos();
+        let t = T::one() - c;
+        [
+            [t * x * x + c, t * x * y + s * z, t * x * z - s * y],
+            [t * x * y - s * z, t * y * y + c, t * y * z + s * x],
+            [t * x * z + s * y, t * y * z - s * x, t * z * z + c],
+        ]
+        .into()
+    }
+
+    // `xy` is how much `y` is added to `x`, `xz` how much `z` is added to `x`,
+    // and so on.
+    pub fn shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        let l = T::one();
+        [[l, yx, zx], [xy, l, zy], [xz, yz, l]].into()
+    }
+
+    // A 3x3 matrix is linear, so it cannot translate: points and vectors
+    // transform the same way and `transform_point` equals `transform_vector`.
+    // Use the 4x4 builders for translations and projections.
+    pub fn transform_point(&self, p: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        p.to_vector() * *self
+    }
+
+    pub fn transform_vector(&self, v: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        v.to_vector() * *self
+    }
+}
+
+// 2D transforms on homogeneous 3x3 matrices mirror the 3D ones on 4x4
+// matrices, with the translation in the last row.
+impl<T: MatrixElement + Float> Matrix<T, 3, 3> {
+    fn from_linear2(m: Matrix<T, 2, 2>) -> Self {
+        let mut result = Self::identity();
+        for r in 0..2 {
+            for c in 0..2 {
+                result.elements[r][c] = m.elements[r][c];
+            }
+        }
+        result
+    }
+
+    pub fn translation2(v: impl VectorLike<T, 2>) -> Self {
+        let mut m = Self::identity();
+        for i in 0..2 {
+            m.elements[2][i] = *v.get(i);
+        }
+        m
+    }
+
+    pub fn scaling2(v: impl VectorLike<T, 2>) -> Self {
+        let mut m = Self::identity();
+        for i in 0..2 {
+            m.elements[i][i] = *v.get(i);
+        }
+        m
+    }
+
+    pub fn uniform_scaling2(s: T) -> Self {
+        Self::scaling2([s, s])
+    }
+
+    pub fn rotation2(theta: impl Into<Rad<T>>) -> Self {
+        Self::from_linear2(Matrix::<T, 2, 2>::rotate(theta))
+    }
+
+    // `xy` is how much `y` is added to `x`, and `yx` how much `x` is added
+    // to `y`.
+    pub fn shear2(xy: T, yx: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        [[l, yx, o], [xy, l, o], [o, o, l]].into()
+    }
+
+    // Points are extended with `w = 1` and divided by the resulting `w`.
+    pub fn transform_point2(&self, p: impl VectorLike<T, 2>) -> Vector<T, 2> {
+        let p = p.to_vector();
+        let v = Vector::<T, 3>::new(p.x(), p.y(), T::one()) * *self;
+        v.xy() / v.z()
+    }
+
+    // Vectors are extended with `w = 0`, so they are not translated.
+    pub fn transform_vector2(&self, v: impl VectorLike<T, 2>) -> Vector<T, 2> {
+        let v = v.to_vector();
+        (Vector::<T, 3>::new(v.x(), v.y(), T::zero()) * *self).xy()
+    }
+}
+
+impl<T: MatrixElement + Float> Matrix<T, 4, 4> {
+    fn from_linear(m: Matrix<T, 3, 3>) -> Self {
+        let mut result = Self::identity();
+        for r in 0..3 {
+            for c in 0..3 {
+                result.elements[r][c] = m.elements[r][c];
+            }
+        }
+        result
+    }
+
+    pub fn translation(v: impl VectorLike<T, 3>) -> Self {
+        let mut m = Self::identity();
+        for i in 0..3 {
+            m.elements[3][i] = *v.get(i);
+        }
+        m
+    }
+
+    pub fn scaling(v: impl VectorLike<T, 3>) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::scaling(v))
+    }
+
+    pub fn uniform_scaling(s: T) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::uniform_scaling(s))
+    }
+
+    pub fn rotation_x(theta: impl Into<Rad<T>>) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::rotation_x(theta))
+    }
+
+    pub fn rotation_y(theta: impl Into<Rad<T>>) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::rotation_y(theta))
+    }
+
+    pub fn rotation_z(theta: impl Into<Rad<T>>) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::rotation_z(theta))
+    }
+
+    pub fn rotation_axis_angle(axis: impl VectorLike<T, 3>, theta: impl Into<Rad<T>>) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::rotation_axis_angle(axis, theta))
+    }
+
+    pub fn shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        Self::from_linear(Matrix::<T, 3, 3>::shear(xy, xz, yx, yz, zx, zy))
+    }
+
+    // Points are extended with `w = 1` and divided by the resulting `w`, so
+    // projective matrices are handled as well as affine ones.
+    pub fn transform_point(&self, p: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        let p = p.to_vector();
+        let v = Vector::<T, 4>::new(p.x(), p.y(), p.z(), T::one()) * *self;
+        v.xyz() / v.w()
+    }
+
+    // Vectors are extended with `w = 0`, so they are not translated.
+    pub fn transform_vector(&self, v: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        let v = v.to_vector();
+        (Vector::<T, 4>::new(v.x(), v.y(), v.z(), T::zero()) * *self).xyz()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Deg;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    type Vector2 = Vector<f64, 2>;
+    type Vector3 = Vector<f64, 3>;
+    type Matrix3 = Matrix<f64, 3, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    #[test]
+    fn translation() {
+        let m = Matrix4::translation([1.0, 2.0, 3.0]);
+        let p = Vector3::new(4.0, 5.0, 6.0);
+        assert_approx_eq(m.transform_point(p), Vector3::new(5.0, 7.0, 9.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector(p), p, 1.0e-10);
+        assert_eq!(m.row(3), [1.0, 2.0, 3.0, 1.0].to_vector());
+    }
+
+    #[test]
+    fn scaling() {
+        let p = Vector3::new(1.0, 2.0, 3.0);
+        let m = Matrix4::scaling([2.0, 3.0, 4.0]);
+        assert_approx_eq(m.transform_point(p), Vector3::new(2.0, 6.0, 12.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector(p), Vector3::new(2.0, 6.0, 12.0), 1.0e-10);
+
+        let m = Matrix3::scaling([2.0, 3.0, 4.0]);
+        assert_approx_eq(m.transform_point(p), Vector3::new(2.0, 6.0, 12.0), 1.0e-10);
+    }
+
+    #[test]
+    fn uniform_scaling() {
+        let p = Vector3::new(1.0, 2.0, 3.0);
+        assert_approx_eq(
+            Matrix4::uniform_scaling(2.0).transform_point(p),
+            p * 2.0,
+            1.0e-10,
+        );
+        assert_approx_eq(
+            Matrix3::uniform_scaling(0.5).transform_vector(p),
+            p * 0.5,
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn rotation_x() {
+        let m = Matrix4::rotation_x(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_y()),
+            Vector3::unit_z(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_z()),
+            -Vector3::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            Vector3::unit_x(),
+            1.0e-10,
+        );
+
+        let m = Matrix3::rotation_x(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_y()),
+            Vector3::unit_z(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn rotation_y() {
+        let m = Matrix4::rotation_y(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_z()),
+            Vector3::unit_x(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            -Vector3::unit_z(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_y()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+
+        let m = Matrix3::rotation_y(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_z()),
+            Vector3::unit_x(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn rotation_z() {
+        let m = Matrix4::rotation_z(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_y()),
+            -Vector3::unit_x(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_z()),
+            Vector3::unit_z(),
+            1.0e-10,
+        );
+
+        let m = Matrix3::rotation_z(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn rotation_z_matches_rotate_2x2() {
+        let m3 = Matrix3::rotation_z(0.3);
+        let m2 = Matrix::<f64, 2, 2>::rotate(0.3);
+        for r in 0..2 {
+            for c in 0..2 {
+                assert!((m3[r][c] - m2[r][c]).abs() < 1.0e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn rotation_axis_angle() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        for theta in [0.0, 0.5, FRAC_PI_2, 2.0] {
+            assert_approx_eq(
+                Matrix4::rotation_axis_angle([2.0, 0.0, 0.0], theta).transform_vector(v),
+                Matrix4::rotation_x(theta).transform_vector(v),
+                1.0e-10,
+            );
+            assert_approx_eq(
+                Matrix4::rotation_axis_angle(Vector3::unit_y(), theta).transform_vector(v),
+                Matrix4::rotation_y(theta).transform_vector(v),
+                1.0e-10,
+            );
+            assert_approx_eq(
+                Matrix3::rotation_axis_angle(Vector3::unit_z(), theta).transform_vector(v),
+                Matrix3::rotation_z(theta).transform_vector(v),
+                1.0e-10,
+            );
+        }
+
+        let axis = Vector3::new(1.0, 1.0, 1.0);
+        let m = Matrix4::rotation_axis_angle(axis, Deg(120.0));
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_y()),
+            Vector3::unit_z(),
+            1.0e-10,
+        );
+        assert_approx_eq(m.transform_vector(axis), axis, 1.0e-10);
+    }
+
+    #[test]
+    fn shear() {
+        let p = Vector3::new(1.0, 2.0, 3.0);
+        let m = Matrix4::shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_approx_eq(m.transform_point(p), Vector3::new(3.0, 2.0, 3.0), 1.0e-10);
+
+        let m = Matrix3::shear(0.0, 0.0, 0.0, 2.0, 0.5, 0.0);
+        assert_approx_eq(m.transform_point(p), Vector3::new(1.0, 8.0, 3.5), 1.0e-10);
+    }
+
+    #[test]
+    fn compose() {
+        let p = Vector3::new(1.0, 0.0, 0.0);
+        let m = Matrix4::scaling([2.0, 2.0, 2.0])
+            * Matrix4::rotation_z(FRAC_PI_2)
+            * Matrix4::translation([0.0, 0.0, 5.0]);
+        assert_approx_eq(m.transform_point(p), Vector3::new(0.0, 2.0, 5.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector(p), Vector3::new(0.0, 2.0, 0.0), 1.0e-10);
+    }
+
+    #[test]
+    fn transform_point_divides_by_w() {
+        let mut m = Matrix4::identity();
+        m[3][3] = 2.0;
+        let p = Vector3::new(2.0, 4.0, 6.0);
+        assert_approx_eq(m.transform_point(p), Vector3::new(1.0, 2.0, 3.0), 1.0e-10);
+    }
+
+    #[test]
+    fn translation2() {
+        let m = Matrix3::translation2([1.0, 2.0]);
+        let p = Vector2::new(4.0, 5.0);
+        assert_approx_eq(m.transform_point2(p), Vector2::new(5.0, 7.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector2(p), p, 1.0e-10);
+        assert_eq!(m.row(2), [1.0, 2.0, 1.0].to_vector());
+    }
+
+    #[test]
+    fn scaling2() {
+        let p = Vector2::new(1.0, 2.0);
+        let m = Matrix3::scaling2([2.0, 3.0]);
+        assert_approx_eq(m.transform_point2(p), Vector2::new(2.0, 6.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector2(p), Vector2::new(2.0, 6.0), 1.0e-10);
+        assert_approx_eq(
+            Matrix3::uniform_scaling2(0.5).transform_point2(p),
+            p * 0.5,
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn rotation2() {
+        let m = Matrix3::rotation2(FRAC_PI_2);
+        assert_approx_eq(
+            m.transform_vector2(Vector2::unit_x()),
+            Vector2::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_point2(Vector2::unit_y()),
+            -Vector2::unit_x(),
+            1.0e-10,
+        );
+
+        let v = Vector3::new(1.0, 2.0, 1.0);
+        assert_approx_eq(
+            Matrix3::rotation2(0.3).transform_point(v),
+            Matrix3::rotation_z(0.3).transform_point(v),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn shear2() {
+        let p = Vector2::new(1.0, 2.0);
+        let m = Matrix3::shear2(1.0, 0.0);
+        assert_approx_eq(m.transform_point2(p), Vector2::new(3.0, 2.0), 1.0e-10);
+        let m = Matrix3::shear2(0.0, 2.0);
+        assert_approx_eq(m.transform_point2(p), Vector2::new(1.0, 4.0), 1.0e-10);
+    }
+
+    #[test]
+    fn compose2() {
+        let p = Vector2::new(1.0, 0.0);
+        let m = Matrix3::scaling2([2.0, 2.0])
+            * Matrix3::rotation2(FRAC_PI_2)
+            * Matrix3::translation2([0.0, 5.0]);
+        assert_approx_eq(m.transform_point2(p), Vector2::new(0.0, 7.0), 1.0e-10);
+        assert_approx_eq(m.transform_vector2(p), Vector2::new(0.0, 2.0), 1.0e-10);
+    }
+
+    #[test]
+    fn rotation_accepts_degrees() {
+        assert_eq!(
+            Matrix4::rotation_x(Deg(90.0)),
+            Matrix4::rotation_x(Rad(FRAC_PI_2))
+        );
+        assert_eq!(
+            Matrix4::rotation_y(Deg(90.0)),
+            Matrix4::rotation_y(FRAC_PI_2)
+        );
+        assert_eq!(Matrix3::rotation2(Deg(90.0)), Matrix3::rotation2(FRAC_PI_2));
+        assert_eq!(
+            Matrix::<f32, 3, 3>::rotation_z(Deg(90.0)),
+            Matrix::<f32, 3, 3>::rotation_z(std::f32::consts::FRAC_PI_2)
+        );
+        assert_eq!(
+            Matrix::<f32, 2, 2>::rotate(0.5),
+            Matrix::<f32, 2, 2>::rotate(Rad(0.5))
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use super::*;
+
+impl<T: VectorElement + Float> Vector<T, 3> {
+    fn fallback_up(forward: Self) -> Self {
+        let (x, y, z) = (forward.x().abs(), forward.y().abs(), forward.z().abs());
+        if x <= y && x <= z {
+            Self::unit_x()
+        } else if y <= z {
+            Self::unit_y()
+        } else {
+            Self::unit_z()
+        }
+    }
+
+    fn camera_basis(forward: Self, up: Self, right_handed: bool) -> (Self, Self, Self) {
+        let forward = forward.normalized();
+        let side = |up: Self| {
+            if right_handed {
+                forward.cross(up)
+            } else {
+                up.cross(forward)
+            }
+        };
+        let mut right = side(up);
+        if right.length_squared() <= T::epsilon() * up.length_squared() {
+            right = side(Self::fallback_up(forward));
+        }
+        let right = right.normalized();
+        let up = if right_handed {
+            right.cross(forward)
+        } else {
+            forward.cross(right)
+        };
+        (right, up, forward)
+    }
+
+    // Returns `(right, up, forward)` for a camera looking along `forward`. If
+    // `up` is parallel to `forward`, the world axis least aligned with
+    // `forward` is used as up instead.
+    pub fn camera_basis_rh(
+        forward: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> (Self, Self, Self) {
+        Self::camera_basis(forward.to_vector(), up.to_vector(), true)
+    }
+
+    pub fn camera_basis_lh(
+        forward: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> (Self, Self, Self) {
+        Self::camera_basis(forward.to_vector(), up.to_vector(), false)
+    }
+}
+
+impl<T: MatrixElement + Float> Matrix<T, 4, 4> {
+    fn view(eye: Vector<T, 3>, right: Vector<T, 3>, up: Vector<T, 3>, back: Vector<T, 3>) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        [
+            [right.x(), up.x(), back.x(), o],
+            [right.y(), up.y(), back.y(), o],
+            [right.z(), up.z(), back.z(), o],
+            [-eye.dot(right), -eye.dot(up), -eye.dot(back), l],
+        ]
+        .into()
+    }
+
+    pub fn look_to_rh(
+        eye: impl VectorLike<T, 3>,
+        direction: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> Self {
+        let (right, up, forward) = Vector::camera_basis_rh(direction, up);
+        Self::view(eye.to_vector(), right, up, -forward)
+    }
+
+    pub fn look_to_lh(
+        eye: impl VectorLike<T, 3>,
+        direction: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> Self {
+        let (right, up, forward) = Vector::camera_basis_lh(direction, up);
+        Self::view(eye.to_vector(), right, up, forward)
+    }
+
+    pub fn look_at_rh(
+        eye: impl VectorLike<T, 3>,
+        target: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> Self {
+        let eye = eye.to_vector();
+        Self::look_to_rh(eye, target.to_vector() - eye, up)
+    }
+
+    pub fn look_at_lh(
+        eye: impl VectorLike<T, 3>,
+        target: impl VectorLike<T, 3>,
+        up: impl VectorLike<T, 3>,
+    ) -> Self {
+        let eye = eye.to_vector();
+        Self::look_to_lh(eye, target.to_vector() - eye, up)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    fn assert_orthonormal((right, up, forward): (Vector3, Vector3, Vector3)) {
+        for v in [right, up, forward] {
+            assert!((v.length() - 1.0).abs() < 1.0e-10);
+        }
+        assert!(right.dot(up).abs() < 1.0e-10);
+        assert!(up.dot(forward).abs() < 1.0e-10);
+        assert!(forward.dot(right).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn camera_basis_rh() {
+        let basis = Vector3::camera_basis_rh([0.0, 0.0, -2.0], [0.0, 3.0, 0.0]);
+        assert_approx_eq(basis.0, Vector3::unit_x(), 1.0e-10);
+        assert_approx_eq(basis.1, Vector3::unit_y(), 1.0e-10);
+        assert_approx_eq(basis.2, -Vector3::unit_z(), 1.0e-10);
+
+        let basis = Vector3::camera_basis_rh([1.0, 2.0, 3.0], [0.2, 1.0, 0.0]);
+        assert_orthonormal(basis);
+        assert_approx_eq(basis.0.cross(basis.1), -basis.2, 1.0e-10);
+        assert!(basis.1.dot(Vector3::unit_y()) > 0.0);
+    }
+
+    #[test]
+    fn camera_basis_lh() {
+        let basis = Vector3::camera_basis_lh([0.0, 0.0, 2.0], [0.0, 3.0, 0.0]);
+        assert_approx_eq(basis.0, Vector3::unit_x(), 1.0e-10);
+        assert_approx_eq(basis.1, Vector3::unit_y(), 1.0e-10);
+        assert_approx_eq(basis.2, Vector3::unit_z(), 1.0e-10);
+
+        let basis = Vector3::camera_basis_lh([1.0, 2.0, 3.0], [0.2, 1.0, 0.0]);
+        assert_orthonormal(basis);
+        assert_approx_eq(basis.0.cross(basis.1), basis.2, 1.0e-10);
+    }
+
+    #[test]
+    fn camera_basis_parallel_up() {
+        for forward in [
+            Vector3::unit_y(),
+            -Vector3::unit_y(),
+            Vector3::new(0.0, 1.0, 1.0e-12),
+        ] {
+            let basis = Vector3::camera_basis_rh(forward, Vector3::unit_y());
+            assert_orthonormal(basis);
+            let basis = Vector3::camera_basis_lh(forward, Vector3::unit_y());
+            assert_orthonormal(basis);
+        }
+    }
+
+    #[test]
+    fn look_at_rh() {
+        let eye = Vector3::new(0.0, 0.0, 5.0);
+        let m = Matrix4::look_at_rh(eye, Vector3::zero(), Vector3::unit_y());
+        assert_approx_eq(m.transform_point(eye), Vector3::zero(), 1.0e-10);
+        assert_approx_eq(
+            m.transform_point(Vector3::zero()),
+            Vector3::new(0.0, 0.0, -5.0),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_point([1.0, 2.0, 5.0]),
+            Vector3::new(1.0, 2.0, 0.0),
+            1.0e-10,
+        );
+
+        let eye = Vector3::new(3.0, 4.0, -2.0);
+        let target = Vector3::new(-1.0, 0.5, 2.0);
+        let m = Matrix4::look_at_rh(eye, target, Vector3::unit_y());
+        let distance = eye.distance(target);
+        assert_approx_eq(
+            m.transform_point(target),
+            Vector3::new(0.0, 0.0, -distance),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn look_at_lh() {
+        let eye = Vector3::new(0.0, 0.0, -5.0);
+        let m = Matrix4::look_at_lh(eye, Vector3::zero(), Vector3::unit_y());
+        assert_approx_eq(m.transform_point(eye), Vector3::zero(), 1.0e-10);
+        assert_approx_eq(
+            m.transform_point(Vector3::zero()),
+            Vector3::new(0.0, 0.0, 5.0),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_point([1.0, 2.0, -5.0]),
+            Vector3::new(1.0, 2.0, 0.0),
+            1.0e-10,
+        );
+
+        let eye = Vector3::new(3.0, 4.0, -2.0);
+        let target = Vector3::new(-1.0, 0.5, 2.0);
+        let m = Matrix4::look_at_lh(eye, target, Vector3::unit_y());
+        let distance = eye.distance(target);
+        assert_approx_eq(
+            m.transform_point(target),
+            Vector3::new(0.0, 0.0, distance),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn look_to() {
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        let direction = Vector3::new(0.0, -1.0, -1.0);
+        let target = eye + direction;
+        let up = Vector3::unit_y();
+        assert_eq!(
+            Matrix4::look_to_rh(eye, direction, up),
+            Matrix4::look_at_rh(eye, target, up)
+        );
+        assert_eq!(
+            Matrix4::look_to_lh(eye, direction, up),
+            Matrix4::look_at_lh(eye, target, up)
+        );
+
+        let m = Matrix4::look_to_rh(eye, Vector3::unit_x(), Vector3::unit_y());
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_x()),
+            -Vector3::unit_z(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            m.transform_vector(Vector3::unit_z()),
+            Vector3::unit_x(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn look_at_parallel_up() {
+        let m = Matrix4::look_at_rh([0.0, 10.0, 0.0], [0.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+        assert!(m.fold(true, |ok, e| ok && e.is_finite()));
+        assert_approx_eq(
+            m.transform_point(Vector3::zero()),
+            Vector3::new(0.0, 0.0, -10.0),
+            1.0e-10,
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::math::vector::*;
+use num::Float;
+use std::ops::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Normal<T: VectorElement, const DIMENSION: usize> {
+    vector: Vector<T, DIMENSION>,
+}
+
+impl<T, const DIMENSION: usize> Eq for Normal<T, DIMENSION> where T: VectorElement + Eq {}
+
+impl<T: VectorElement, const DIMENSION: usize> From<[T; DIMENSION]> for Normal<T, DIMENSION> {
+    fn from(v: [T; DIMENSION]) -> Self {
+        Self { vector: v.into() }
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> From<Vector<T, DIMENSION>> for Normal<T, DIMENSION> {
+    fn from(vector: Vector<T, DIMENSION>) -> Self {
+        Self { vector }
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> From<Normal<T, DIMENSION>> for Vector<T, DIMENSION> {
+    fn from(n: Normal<T, DIMENSION>) -> Self {
+        n.vector
+    }
+}
+
+impl<T: VectorElement> Normal<T, 2> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector::<T, 2>::new(x, y).into()
+    }
+}
+
+impl<T: VectorElement> Normal<T, 3> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector::<T, 3>::new(x, y, z).into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Normal<T, DIMENSION> {
+    pub fn vector(&self) -> Vector<T, DIMENSION> {
+        self.vector
+    }
+
+    pub fn dot(&self, v: impl VectorLike<T, DIMENSION>) -> T {
+        self.vector.dot(v)
+    }
+}
+
+impl<T, const DIMENSION: usize> Normal<T, DIMENSION>
+where
+    T: VectorElement + Float,
+{
+    pub fn normalized(&self) -> Self {
+        self.vector.normalized().into()
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+}
+
+impl<T, const DIMENSION: usize> Neg for Normal<T, DIMENSION>
+where
+    T: VectorElement + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        (-self.vector).into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Index<usize> for Normal<T, DIMENSION> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.vector[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Normal3<T> = Normal<T, 3>;
+    type Vector3<T> = Vector<T, 3>;
+
+    #[test]
+    fn new() {
+        let n = Normal3::new(0, 1, 0);
+        assert_eq!(n.vector(), Vector3::unit_y());
+        assert_eq!(Normal3::from([0, 1, 0]), n);
+        assert_eq!(Normal3::from(Vector3::unit_y()), n);
+        assert_eq!(Vector3::from(n), Vector3::unit_y());
+        assert_eq!(Normal::<i32, 2>::new(1, 0)[0], 1);
+    }
+
+    #[test]
+    fn normalized() {
+        let n = Normal3::new(0.0, 3.0, 4.0);
+        assert_eq!(n.normalized(), Normal3::new(0.0, 0.6, 0.8));
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-Normal3::new(1, -2, 3), Normal3::new(-1, 2, -3));
+    }
+
+    #[test]
+    fn dot() {
+        let n = Normal3::new(0, 1, 0);
+        assert_eq!(n.dot([3, 4, 5]), 4);
+        assert_eq!(Vector3::new(3, 4, 5).dot(n.vector()), 4);
+    }
+}
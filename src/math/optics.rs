@@ -0,0 +1,174 @@
+use crate::math::vector::*;
+use num::Float;
+
+impl<T: VectorElement + Float> Vector<T, 3> {
+    // Follows GLSL: `self` is the normalized incident direction pointing
+    // towards the surface, `normal` the unit normal facing against it, and
+    // `eta` the ratio of the incident to the transmitted index of refraction.
+    // Returns `None` on total internal reflection.
+    pub fn refract(&self, normal: impl VectorLike<T, 3>, eta: T) -> Option<Self> {
+        let normal = normal.to_vector();
+        let cos_i = -self.dot(normal);
+        let k = T::one() - eta * eta * (T::one() - cos_i * cos_i);
+        if k < T::zero() {
+            return None;
+        }
+        Some(*self * eta + normal * (eta * cos_i - k.sqrt()))
+    }
+}
+
+// The fraction of unpolarized light reflected at the boundary between two
+// dielectrics. `cos_i` is the cosine of the angle between the incident
+// direction and the normal on the side of `eta_i`; a negative cosine means the
+// light arrives from the side of `eta_t`. Total internal reflection gives one.
+pub fn fresnel_dielectric<T: Float>(cos_i: T, eta_i: T, eta_t: T) -> T {
+    let cos_i = cos_i.max(-T::one()).min(T::one());
+    let (cos_i, eta_i, eta_t) = if cos_i < T::zero() {
+        (-cos_i, eta_t, eta_i)
+    } else {
+        (cos_i, eta_i, eta_t)
+    };
+    let sin_i = (T::one() - cos_i * cos_i).max(T::zero()).sqrt();
+    let sin_t = eta_i / eta_t * sin_i;
+    if sin_t >= T::one() {
+        return T::one();
+    }
+    let cos_t = (T::one() - sin_t * sin_t).sqrt();
+    let parallel = (eta_t * cos_i - eta_i * cos_t) / (eta_t * cos_i + eta_i * cos_t);
+    let perpendicular = (eta_i * cos_i - eta_t * cos_t) / (eta_i * cos_i + eta_t * cos_t);
+    (parallel * parallel + perpendicular * perpendicular) / (T::one() + T::one())
+}
+
+// The fraction of unpolarized light reflected by a conductor with the complex
+// index of refraction `eta + i k`, relative to the incident medium. `cos_i` is
+// the cosine of the angle of incidence and must be non-negative.
+pub fn fresnel_conductor<T: Float>(cos_i: T, eta: T, k: T) -> T {
+    let two = T::one() + T::one();
+    let half = two.recip();
+    let cos_i = cos_i.max(T::zero()).min(T::one());
+    let cos2 = cos_i * cos_i;
+    let sin2 = T::one() - cos2;
+    let eta2 = eta * eta;
+    let k2 = k * k;
+
+    let t0 = eta2 - k2 - sin2;
+    let a2_plus_b2 = (t0 * t0 + two * two * eta2 * k2).sqrt();
+    let t1 = a2_plus_b2 + cos2;
+    let a = (half * (a2_plus_b2 + t0)).max(T::zero()).sqrt();
+    let t2 = two * cos_i * a;
+    let perpendicular = (t1 - t2) / (t1 + t2);
+
+    let t3 = cos2 * a2_plus_b2 + sin2 * sin2;
+    let t4 = t2 * sin2;
+    let parallel = perpendicular * (t3 - t4) / (t3 + t4);
+    half * (parallel + perpendicular)
+}
+
+// Schlick's approximation of the Fresnel reflectance, with `f0` the
+// reflectance at normal incidence.
+pub fn fresnel_schlick<T: Float>(cos_i: T, f0: T) -> T {
+    let m = (T::one() - cos_i).max(T::zero()).min(T::one());
+    let m2 = m * m;
+    f0 + (T::one() - f0) * m2 * m2 * m
+}
+
+// The reflectance at normal incidence between two dielectrics, for use with
+// `fresnel_schlick`.
+pub fn fresnel_f0<T: Float>(eta_i: T, eta_t: T) -> T {
+    let r = (eta_i - eta_t) / (eta_i + eta_t);
+    r * r
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Vector3 = Vector<f64, 3>;
+
+    const GLASS: f64 = 1.5;
+
+    fn incident(degrees: f64) -> Vector3 {
+        let theta = degrees.to_radians();
+        Vector3::new(theta.sin(), -theta.cos(), 0.0)
+    }
+
+    #[test]
+    fn refract_snell() {
+        let n = Vector3::unit_y();
+        for degrees in [0.0, 15.0, 30.0, 45.0, 60.0, 89.0] {
+            let t = incident(degrees).refract(n, 1.0 / GLASS).unwrap();
+            assert!((t.length() - 1.0).abs() < 1.0e-12);
+            assert!(t.y() < 0.0);
+            let sin_t = t.x();
+            assert!((sin_t * GLASS - degrees.to_radians().sin()).abs() < 1.0e-12);
+        }
+
+        // 45 degrees from air into glass bends to about 28.1255 degrees.
+        let t = incident(45.0).refract(n, 1.0 / GLASS).unwrap();
+        assert!((t.x().asin().to_degrees() - 28.125_5).abs() < 1.0e-4);
+
+        let straight = incident(0.0).refract(n, 1.0 / GLASS).unwrap();
+        assert!((straight - Vector3::new(0.0, -1.0, 0.0)).length() < 1.0e-12);
+        assert_eq!(incident(30.0).refract(n, 1.0), Some(incident(30.0)));
+    }
+
+    #[test]
+    fn total_internal_reflection() {
+        let n = Vector3::unit_y();
+        let critical = (1.0 / GLASS).asin().to_degrees();
+        assert!((critical - 41.810_3).abs() < 1.0e-4);
+        assert!(incident(critical - 0.1).refract(n, GLASS).is_some());
+        assert_eq!(incident(critical + 0.1).refract(n, GLASS), None);
+        assert_eq!(incident(80.0).refract(n, GLASS), None);
+
+        let cos_i = (critical + 0.1).to_radians().cos();
+        assert_eq!(fresnel_dielectric(cos_i, GLASS, 1.0), 1.0);
+        assert_eq!(fresnel_dielectric(-cos_i, 1.0, GLASS), 1.0);
+        assert!(fresnel_dielectric((critical - 0.1).to_radians().cos(), GLASS, 1.0) < 1.0);
+    }
+
+    #[test]
+    fn dielectric() {
+        assert!((fresnel_dielectric(1.0, 1.0, GLASS) - 0.04).abs() < 1.0e-12);
+        assert!((fresnel_dielectric(1.0, GLASS, 1.0) - 0.04).abs() < 1.0e-12);
+        assert!((fresnel_dielectric(0.0, 1.0, GLASS) - 1.0).abs() < 1.0e-12);
+        assert_eq!(fresnel_dielectric(0.7, 1.0, 1.0), 0.0);
+
+        // At Brewster's angle only the perpendicular polarization is reflected.
+        let brewster = GLASS.atan();
+        let cos_t = (brewster.sin() / GLASS).asin().cos();
+        let perpendicular = (brewster.cos() - GLASS * cos_t) / (brewster.cos() + GLASS * cos_t);
+        let r = fresnel_dielectric(brewster.cos(), 1.0, GLASS);
+        assert!((r - perpendicular * perpendicular / 2.0).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn conductor() {
+        for degrees in [0.0, 20.0, 45.0, 70.0, 85.0] {
+            let cos_i = f64::to_radians(degrees).cos();
+            let conductor = fresnel_conductor(cos_i, GLASS, 0.0);
+            let dielectric = fresnel_dielectric(cos_i, 1.0, GLASS);
+            assert!((conductor - dielectric).abs() < 1.0e-12);
+        }
+
+        // Gold at 550nm, `((n - 1)^2 + k^2) / ((n + 1)^2 + k^2)` at normal
+        // incidence.
+        let (eta, k) = (0.424_f64, 2.346);
+        let normal = ((eta - 1.0).powi(2) + k * k) / ((eta + 1.0).powi(2) + k * k);
+        assert!((fresnel_conductor(1.0, eta, k) - normal).abs() < 1.0e-12);
+        assert!((fresnel_conductor(0.0, eta, k) - 1.0).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn schlick() {
+        let f0 = fresnel_f0(1.0, GLASS);
+        assert!((f0 - 0.04).abs() < 1.0e-12);
+        assert_eq!(fresnel_schlick(1.0, f0), f0);
+        assert_eq!(fresnel_schlick(0.0, f0), 1.0);
+        for degrees in [0.0, 30.0, 45.0] {
+            let cos_i = f64::to_radians(degrees).cos();
+            let exact = fresnel_dielectric(cos_i, 1.0, GLASS);
+            assert!((fresnel_schlick(cos_i, f0) - exact).abs() < 1.0e-2);
+        }
+    }
+}
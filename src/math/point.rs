@@ -0,0 +1,211 @@
+use crate::math::vector::*;
+use num::{Float, Zero};
+use std::ops::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point<T: VectorElement, const DIMENSION: usize> {
+    coords: Vector<T, DIMENSION>,
+}
+
+impl<T, const DIMENSION: usize> Eq for Point<T, DIMENSION> where T: VectorElement + Eq {}
+
+impl<T: VectorElement, const DIMENSION: usize> From<[T; DIMENSION]> for Point<T, DIMENSION> {
+    fn from(v: [T; DIMENSION]) -> Self {
+        Self { coords: v.into() }
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> From<Vector<T, DIMENSION>> for Point<T, DIMENSION> {
+    fn from(coords: Vector<T, DIMENSION>) -> Self {
+        Self { coords }
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> From<Point<T, DIMENSION>> for Vector<T, DIMENSION> {
+    fn from(p: Point<T, DIMENSION>) -> Self {
+        p.coords
+    }
+}
+
+impl<T: VectorElement> From<(T, T)> for Point<T, 2> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: VectorElement> From<(T, T, T)> for Point<T, 3> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T: VectorElement> Point<T, 2> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector::<T, 2>::new(x, y).into()
+    }
+}
+
+impl<T: VectorElement> Point<T, 3> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector::<T, 3>::new(x, y, z).into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Point<T, DIMENSION> {
+    pub fn origin() -> Self {
+        Vector::zero().into()
+    }
+
+    pub fn coords(&self) -> Vector<T, DIMENSION> {
+        self.coords
+    }
+
+    pub fn coords_mut(&mut self) -> &mut Vector<T, DIMENSION> {
+        &mut self.coords
+    }
+
+    pub fn distance_squared(&self, other: Self) -> T {
+        (*self - other).length_squared()
+    }
+}
+
+impl<T, const DIMENSION: usize> Point<T, DIMENSION>
+where
+    T: VectorElement + Float,
+{
+    pub fn distance(&self, other: Self) -> T {
+        (*self - other).length()
+    }
+
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Sub<Self> for Point<T, DIMENSION> {
+    type Output = Vector<T, DIMENSION>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.coords - rhs.coords
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Add<Vector<T, DIMENSION>> for Point<T, DIMENSION> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector<T, DIMENSION>) -> Self::Output {
+        (self.coords + rhs).into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Sub<Vector<T, DIMENSION>> for Point<T, DIMENSION> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<T, DIMENSION>) -> Self::Output {
+        (self.coords - rhs).into()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> AddAssign<Vector<T, DIMENSION>>
+    for Point<T, DIMENSION>
+{
+    fn add_assign(&mut self, rhs: Vector<T, DIMENSION>) {
+        self.coords += rhs;
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> SubAssign<Vector<T, DIMENSION>>
+    for Point<T, DIMENSION>
+{
+    fn sub_assign(&mut self, rhs: Vector<T, DIMENSION>) {
+        self.coords -= rhs;
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Index<usize> for Point<T, DIMENSION> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coords[index]
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> IndexMut<usize> for Point<T, DIMENSION> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.coords[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type Point2<T> = Point<T, 2>;
+    type Point3<T> = Point<T, 3>;
+    type Vector3<T> = Vector<T, 3>;
+
+    #[test]
+    fn new() {
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.coords(), Vector3::new(1, 2, 3));
+        assert_eq!(Point::<i32, 2>::new(1, 2).coords(), [1, 2].into());
+        assert_eq!(Point3::from([1, 2, 3]), p);
+        assert_eq!(Point3::from(Vector3::new(1, 2, 3)), p);
+        assert_eq!(Vector3::from(p), Vector3::new(1, 2, 3));
+        assert_eq!(Point3::<i32>::origin(), Point3::new(0, 0, 0));
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Point::from((1, 2)), Point2::new(1, 2));
+        assert_eq!(Point::from((1, 2, 3)), Point3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn sub_point() {
+        let p1 = Point3::new(5, 3, 7);
+        let p2 = Point3::new(1, 2, 4);
+        let v: Vector3<i32> = p1 - p2;
+        assert_eq!(v, Vector3::new(4, 1, 3));
+    }
+
+    #[test]
+    fn add_vector() {
+        let mut p = Point3::new(1, 2, 4);
+        let v = Vector3::new(5, 3, 7);
+        assert_eq!(p + v, Point3::new(6, 5, 11));
+        assert_eq!(p - v, Point3::new(-4, -1, -3));
+        p += v;
+        assert_eq!(p, Point3::new(6, 5, 11));
+        p -= v;
+        assert_eq!(p, Point3::new(1, 2, 4));
+    }
+
+    #[test]
+    fn distance() {
+        let p1 = Point3::new(1.0, 2.0, 3.0);
+        let p2 = Point3::new(4.0, 6.0, 3.0);
+        assert_eq!(p1.distance(p2), 5.0);
+        assert_eq!(p1.distance_squared(p2), 25.0);
+    }
+
+    #[test]
+    fn lerp() {
+        let p1 = Point3::new(1.0, 2.0, 3.0);
+        let p2 = Point3::new(3.0, 6.0, -1.0);
+        assert_eq!(p1.lerp(p2, 0.5), Point3::new(2.0, 4.0, 1.0));
+        assert_eq!(p1.lerp(p2, 0.0), p1);
+        assert_eq!(p1.lerp(p2, 1.0), p2);
+    }
+
+    #[test]
+    fn index() {
+        let mut p = Point3::new(1, 2, 3);
+        assert_eq!(p[1], 2);
+        p[1] = 5;
+        *p.coords_mut() += Vector3::new(1, 1, 1);
+        assert_eq!(p, Point3::new(2, 6, 4));
+        assert_eq!(p.coords(), Vector3::new(2, 6, 4));
+    }
+}
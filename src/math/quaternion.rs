@@ -1,3 +1,7 @@
+use crate::math::angle::Rad;
+use crate::math::matrix::*;
+use crate::math::vector::*;
+use num::traits::AsPrimitive;
 use num::Float;
 use std::ops;
 
@@ -14,6 +18,232 @@ pub struct Quaternion<T: Float> {
     pub w: T,
 }
 
+impl<T: Float> Quaternion<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn dot(&self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    pub fn normalized(&self) -> Self {
+        let len = self.length();
+        if len.is_zero() {
+            *self
+        } else {
+            *self * (T::one() / len)
+        }
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    pub fn inverse(&self) -> Self {
+        self.conjugate() * (T::one() / self.length_squared())
+    }
+}
+
+// Element conversions follow `Vector::cast`, `Vector::try_cast` and
+// `Vector::saturating_cast`.
+impl<T: Float> Quaternion<T> {
+    pub fn cast<U>(self) -> Quaternion<U>
+    where
+        T: AsPrimitive<U>,
+        U: Float + 'static,
+    {
+        Quaternion::new(self.x.as_(), self.y.as_(), self.z.as_(), self.w.as_())
+    }
+
+    pub fn try_cast<U: Float>(self) -> Option<Quaternion<U>> {
+        Some(Quaternion::new(
+            cast_element(self.x)?,
+            cast_element(self.y)?,
+            cast_element(self.z)?,
+            cast_element(self.w)?,
+        ))
+    }
+
+    pub fn saturating_cast<U: Float>(self) -> Quaternion<U> {
+        let saturate = |e: T| {
+            cast_element(e).unwrap_or_else(|| {
+                if e < T::zero() {
+                    U::min_value()
+                } else if e > T::zero() {
+                    U::max_value()
+                } else {
+                    U::zero()
+                }
+            })
+        };
+        Quaternion::new(
+            saturate(self.x),
+            saturate(self.y),
+            saturate(self.z),
+            saturate(self.w),
+        )
+    }
+}
+
+impl From<Quaternion<f32>> for Quaternion<f64> {
+    fn from(q: Quaternion<f32>) -> Self {
+        Self::new(q.x.into(), q.y.into(), q.z.into(), q.w.into())
+    }
+}
+
+// `a * b` is the Hamilton product, so it rotates by `b` first and then by `a`.
+// Rotation matrices built from a quaternion follow the row-vector convention
+// of `Vector * Matrix`, which means `(a * b).to_matrix3()` equals
+// `b.to_matrix3() * a.to_matrix3()`.
+impl<T: Float + VectorElement> Quaternion<T> {
+    pub fn from_axis_angle(axis: impl VectorLike<T, 3>, theta: impl Into<Rad<T>>) -> Self {
+        let axis = axis.to_vector().normalized();
+        let (s, c) = (theta.into() / (T::one() + T::one())).sin_cos();
+        Self::new(axis.x() * s, axis.y() * s, axis.z() * s, c)
+    }
+
+    // `m` must be a rotation matrix in the row-vector convention, such as one
+    // returned by `to_matrix3`.
+    pub fn from_matrix3(m: Matrix<T, 3, 3>) -> Self {
+        let m = m.transpose();
+        let l = T::one();
+        let quarter = l / (l + l + l + l);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > T::zero() {
+            let s = (trace + l).sqrt() * (l + l);
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+                s * quarter,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (l + m[0][0] - m[1][1] - m[2][2]).sqrt() * (l + l);
+            Self::new(
+                s * quarter,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[2][1] - m[1][2]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (l + m[1][1] - m[0][0] - m[2][2]).sqrt() * (l + l);
+            Self::new(
+                (m[0][1] + m[1][0]) / s,
+                s * quarter,
+                (m[1][2] + m[2][1]) / s,
+                (m[0][2] - m[2][0]) / s,
+            )
+        } else {
+            let s = (l + m[2][2] - m[0][0] - m[1][1]).sqrt() * (l + l);
+            Self::new(
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s * quarter,
+                (m[1][0] - m[0][1]) / s,
+            )
+        };
+        q.normalized()
+    }
+
+    pub fn vector_part(&self) -> Vector<T, 3> {
+        Vector::<T, 3>::new(self.x, self.y, self.z)
+    }
+
+    pub fn rotate_vector(&self, v: impl VectorLike<T, 3>) -> Vector<T, 3> {
+        let v = v.to_vector();
+        let q = self.vector_part();
+        let two = T::one() + T::one();
+        let t = q.cross(v) * two;
+        v + t * self.w + q.cross(t)
+    }
+
+    pub fn to_matrix3(&self) -> Matrix<T, 3, 3> {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let l = T::one();
+        let two = l + l;
+        [
+            [
+                l - two * (y * y + z * z),
+                two * (x * y + z * w),
+                two * (x * z - y * w),
+            ],
+            [
+                two * (x * y - z * w),
+                l - two * (x * x + z * z),
+                two * (y * z + x * w),
+            ],
+            [
+                two * (x * z + y * w),
+                two * (y * z - x * w),
+                l - two * (x * x + y * y),
+            ],
+        ]
+        .into()
+    }
+
+    pub fn to_matrix4(&self) -> Matrix<T, 4, 4> {
+        let m = self.to_matrix3();
+        let mut result = Matrix::<T, 4, 4>::identity();
+        for r in 0..3 {
+            for c in 0..3 {
+                result[r][c] = m[r][c];
+            }
+        }
+        result
+    }
+}
+
+impl<T: Float> ops::Mul<Self> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl<T: Float> ops::Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(
+            self.x * scalar,
+            self.y * scalar,
+            self.z * scalar,
+            self.w * scalar,
+        )
+    }
+}
+
+impl<T: Float> ops::Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
 impl<T: Float> ops::Index<usize> for Quaternion<T> {
     type Output = T;
 
@@ -39,3 +269,138 @@ impl<T: Float> ops::IndexMut<usize> for Quaternion<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Deg;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    type Vector3 = Vector<f64, 3>;
+
+    #[test]
+    fn index() {
+        let mut q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!([q[0], q[1], q[2], q[3]], [1.0, 2.0, 3.0, 4.0]);
+        q[3] = 5.0;
+        assert_eq!(q.w, 5.0);
+    }
+
+    #[test]
+    fn mul() {
+        let i = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+        let j = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        let k = Quaternion::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(i * j, k);
+        assert_eq!(j * k, i);
+        assert_eq!(k * i, j);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, Quaternion::new(0.0, 0.0, 0.0, -1.0));
+        assert_eq!(i * Quaternion::identity(), i);
+    }
+
+    #[test]
+    fn inverse() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        let p = q * q.inverse();
+        assert!((p.w - 1.0).abs() < 1.0e-12);
+        assert!(p.vector_part().length() < 1.0e-12);
+        assert!((q.normalized().length() - 1.0).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn rotate_vector() {
+        let q = Quaternion::from_axis_angle(Vector3::unit_z(), FRAC_PI_2);
+        assert_approx_eq(
+            q.rotate_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            q.rotate_vector([0.0, 0.0, 2.0]),
+            Vector3::new(0.0, 0.0, 2.0),
+            1.0e-10,
+        );
+
+        let q = Quaternion::from_axis_angle([1.0, 1.0, 1.0], Deg(120.0));
+        assert_approx_eq(
+            q.rotate_vector(Vector3::unit_x()),
+            Vector3::unit_y(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn to_matrix() {
+        let axis = Vector3::new(1.0, -2.0, 0.5);
+        let v = Vector3::new(0.3, 1.0, -2.0);
+        for theta in [0.0, 0.4, FRAC_PI_2, 3.0] {
+            let q = Quaternion::from_axis_angle(axis, theta);
+            let m = Matrix::<f64, 3, 3>::rotation_axis_angle(axis, theta);
+            assert_approx_eq(q.rotate_vector(v), v * m, 1.0e-10);
+            assert_approx_eq(v * q.to_matrix3(), v * m, 1.0e-10);
+            assert_approx_eq(q.to_matrix4().transform_point(v), v * m, 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn from_matrix3() {
+        let v = Vector3::new(0.3, 1.0, -2.0);
+        let axes = [
+            Vector3::new(1.0, -2.0, 0.5),
+            Vector3::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_z(),
+        ];
+        for axis in axes {
+            for theta in [0.0, 0.4, FRAC_PI_2, 3.0, PI] {
+                let q = Quaternion::from_axis_angle(axis, theta);
+                let m = q.to_matrix3();
+                let from_m = Quaternion::from_matrix3(m);
+                assert!((from_m.dot(q).abs() - 1.0).abs() < 1.0e-10);
+                assert_approx_eq(from_m.rotate_vector(v), v * m, 1.0e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn composition_order() {
+        let a = Quaternion::from_axis_angle(Vector3::unit_x(), 0.7);
+        let b = Quaternion::from_axis_angle(Vector3::unit_y(), -1.2);
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert_approx_eq(
+            (a * b).rotate_vector(v),
+            a.rotate_vector(b.rotate_vector(v)),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            v * (a * b).to_matrix3(),
+            v * b.to_matrix3() * a.to_matrix3(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn cast() {
+        let q = Quaternion::new(0.5_f64, -0.5, 1.0e300, 0.25);
+        assert_eq!(q.cast::<f32>().x, 0.5_f32);
+        assert_eq!(q.cast::<f32>().z, f32::INFINITY);
+        assert_eq!(q.try_cast::<f32>(), None);
+        assert_eq!(
+            q.saturating_cast::<f32>(),
+            Quaternion::new(0.5, -0.5, f32::MAX, 0.25)
+        );
+
+        let q = Quaternion::new(-1.0e300_f64, f64::INFINITY, f64::NAN, 0.0);
+        assert_eq!(q.try_cast::<f32>(), None);
+        let q = q.saturating_cast::<f32>();
+        assert_eq!((q.x, q.y), (f32::MIN, f32::INFINITY));
+        assert!(q.z.is_nan());
+
+        let q = Quaternion::new(0.5_f32, -0.5, 0.5, 0.5);
+        let wide: Quaternion<f64> = q.into();
+        assert_eq!(wide, Quaternion::new(0.5, -0.5, 0.5, 0.5));
+        assert_eq!(wide.try_cast::<f32>(), Some(q));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::math::angle::Rad;
+use crate::math::matrix::*;
+use crate::math::vector::*;
+use num::Float;
+use std::ops;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// A 2D rotation stored as the unit complex number `cos + i sin`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rotation2<T: Float> {
+    cos: T,
+    sin: T,
+}
+
+impl<T: Float> Default for Rotation2<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> Rotation2<T> {
+    pub fn new(theta: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = theta.into().sin_cos();
+        Self { cos, sin }
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            cos: T::one(),
+            sin: T::zero(),
+        }
+    }
+
+    // `(cos, sin)` is normalized, so any non-zero direction can be passed.
+    pub fn from_cos_sin(cos: T, sin: T) -> Self {
+        let len = cos.hypot(sin);
+        if len.is_zero() {
+            Self::identity()
+        } else {
+            Self {
+                cos: cos / len,
+                sin: sin / len,
+            }
+        }
+    }
+
+    pub fn cos(&self) -> T {
+        self.cos
+    }
+
+    pub fn sin(&self) -> T {
+        self.sin
+    }
+
+    // In `(-pi, pi]`.
+    pub fn angle(&self) -> T {
+        self.sin.atan2(self.cos)
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    // Interpolates along the shorter arc.
+    pub fn slerp(&self, other: Self, t: T) -> Self {
+        *self * Self::new((self.inverse() * other).angle() * t)
+    }
+}
+
+impl<T: Float + VectorElement> Rotation2<T> {
+    pub fn rotate_vector(&self, v: impl VectorLike<T, 2>) -> Vector<T, 2> {
+        let v = v.to_vector();
+        Vector::<T, 2>::new(
+            v.x() * self.cos - v.y() * self.sin,
+            v.x() * self.sin + v.y() * self.cos,
+        )
+    }
+
+    pub fn to_matrix2(&self) -> Matrix<T, 2, 2> {
+        [[self.cos, self.sin], [-self.sin, self.cos]].into()
+    }
+
+    pub fn to_matrix3(&self) -> Matrix<T, 3, 3> {
+        let (o, l) = (T::zero(), T::one());
+        [[self.cos, self.sin, o], [-self.sin, self.cos, o], [o, o, l]].into()
+    }
+}
+
+impl<T: Float> ops::Mul<Self> for Rotation2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+impl<T: Float> ops::MulAssign<Self> for Rotation2<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    type Vector2 = Vector<f64, 2>;
+
+    #[test]
+    fn new() {
+        let r = Rotation2::new(FRAC_PI_2);
+        assert!(r.cos().abs() < 1.0e-10);
+        assert!((r.sin() - 1.0).abs() < 1.0e-10);
+        assert!((r.angle() - FRAC_PI_2).abs() < 1.0e-10);
+        assert_eq!(Rotation2::<f64>::default(), Rotation2::identity());
+
+        let r = Rotation2::from_cos_sin(3.0, 4.0);
+        assert!((r.cos() - 0.6).abs() < 1.0e-10);
+        assert!((r.sin() - 0.8).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn rotate_vector() {
+        let r = Rotation2::new(FRAC_PI_2);
+        assert_approx_eq(
+            r.rotate_vector(Vector2::unit_x()),
+            Vector2::unit_y(),
+            1.0e-10,
+        );
+        assert_approx_eq(r.rotate_vector([0.0, 1.0]), -Vector2::unit_x(), 1.0e-10);
+
+        let r = Rotation2::new(0.7);
+        let v = Vector2::new(1.0, 2.0);
+        assert_approx_eq(r.rotate_vector(v), v * r.to_matrix2(), 1.0e-10);
+        assert_approx_eq(
+            r.rotate_vector(v),
+            r.to_matrix3().transform_point2(v),
+            1.0e-10,
+        );
+        assert_eq!(r.to_matrix3(), Matrix::<f64, 3, 3>::rotation2(0.7));
+    }
+
+    #[test]
+    fn compose() {
+        let a = Rotation2::new(0.3);
+        let b = Rotation2::new(0.5);
+        assert!(((a * b).angle() - 0.8).abs() < 1.0e-10);
+        assert!(((a * a.inverse()).angle()).abs() < 1.0e-10);
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    #[test]
+    fn slerp() {
+        let a = Rotation2::new(0.2);
+        let b = Rotation2::new(1.0);
+        assert!((a.slerp(b, 0.5).angle() - 0.6).abs() < 1.0e-10);
+        assert!((a.slerp(b, 0.0).angle() - 0.2).abs() < 1.0e-10);
+        assert!((a.slerp(b, 1.0).angle() - 1.0).abs() < 1.0e-10);
+
+        // The shorter arc crosses the angle pi.
+        let a = Rotation2::new(PI - 0.1);
+        let b = Rotation2::new(-PI + 0.1);
+        let mid = a.slerp(b, 0.5);
+        assert!((mid.cos() + 1.0).abs() < 1.0e-10);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::math::matrix::*;
+use crate::math::vector::*;
+use num::Float;
+
+// Screen coordinates are in pixels with the origin at the top-left corner of
+// the target and `+Y` down. Screen depth is in `[0, 1]` whatever the NDC depth
+// range of the projection, like the default `glDepthRange`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Viewport<T> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
+}
+
+impl<T: Float> Viewport<T> {
+    pub fn new(x: T, y: T, width: T, height: T) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_size(width: T, height: T) -> Self {
+        Self::new(T::zero(), T::zero(), width, height)
+    }
+
+    pub fn aspect(&self) -> T {
+        self.width / self.height
+    }
+}
+
+fn ndc_to_depth<T: Float>(z: T, depth_range: DepthRange) -> T {
+    match depth_range {
+        DepthRange::NegativeOneToOne => (z + T::one()) / (T::one() + T::one()),
+        DepthRange::ZeroToOne => z,
+    }
+}
+
+fn depth_to_ndc<T: Float>(depth: T, depth_range: DepthRange) -> T {
+    match depth_range {
+        DepthRange::NegativeOneToOne => depth * (T::one() + T::one()) - T::one(),
+        DepthRange::ZeroToOne => depth,
+    }
+}
+
+// Returns `None` for points on or behind the plane of the eye.
+pub fn project<T: MatrixElement + Float>(
+    point: impl VectorLike<T, 3>,
+    view_proj: Matrix<T, 4, 4>,
+    viewport: Viewport<T>,
+    depth_range: DepthRange,
+) -> Option<Vector<T, 3>> {
+    let p = point.to_vector().to_homogeneous::<4>() * view_proj;
+    if p.w() <= T::zero() {
+        return None;
+    }
+    let ndc = p.xyz() / p.w();
+    let two = T::one() + T::one();
+    Some(Vector::<T, 3>::new(
+        viewport.x + (ndc.x() + T::one()) / two * viewport.width,
+        viewport.y + (T::one() - ndc.y()) / two * viewport.height,
+        ndc_to_depth(ndc.z(), depth_range),
+    ))
+}
+
+fn unproject_homogeneous<T: MatrixElement + Float>(
+    screen_point: impl VectorLike<T, 2>,
+    depth: T,
+    inv_view_proj: Matrix<T, 4, 4>,
+    viewport: Viewport<T>,
+    depth_range: DepthRange,
+) -> Vector<T, 4> {
+    let two = T::one() + T::one();
+    let x = (*screen_point.get(0) - viewport.x) / viewport.width * two - T::one();
+    let y = T::one() - (*screen_point.get(1) - viewport.y) / viewport.height * two;
+    let z = depth_to_ndc(depth, depth_range);
+    Vector::<T, 4>::new(x, y, z, T::one()) * inv_view_proj
+}
+
+// Returns `None` if the point is at infinity, which happens at the far plane
+// of an infinite projection.
+pub fn unproject<T: MatrixElement + Float>(
+    screen_point: impl VectorLike<T, 2>,
+    depth: T,
+    inv_view_proj: Matrix<T, 4, 4>,
+    viewport: Viewport<T>,
+    depth_range: DepthRange,
+) -> Option<Vector<T, 3>> {
+    let p = unproject_homogeneous(screen_point, depth, inv_view_proj, viewport, depth_range);
+    if p.w().is_zero() {
+        None
+    } else {
+        Some(Vector::from_homogeneous(p))
+    }
+}
+
+// Returns the ray `(origin, direction)` through a screen point, with the
+// origin on the near plane and a normalized direction pointing away from the
+// eye. The near plane is the one closer to the eye, so reverse-Z and infinite
+// projections are handled as well.
+pub fn ray_from_screen<T: MatrixElement + Float>(
+    screen_point: impl VectorLike<T, 2>,
+    inv_view_proj: Matrix<T, 4, 4>,
+    viewport: Viewport<T>,
+    depth_range: DepthRange,
+) -> Option<(Vector<T, 3>, Vector<T, 3>)> {
+    let screen_point = screen_point.to_vector();
+    let unproject =
+        |depth| unproject_homogeneous(screen_point, depth, inv_view_proj, viewport, depth_range);
+    // The homogeneous `w` of an unprojected point shrinks with its distance
+    // from the eye, and is equal at both planes of an orthographic projection.
+    let (a, b) = (unproject(T::zero()), unproject(T::one()));
+    let near = if b.w().abs() > a.w().abs() { b } else { a };
+    let half = T::one() / (T::one() + T::one());
+    let middle = unproject(half);
+    if near.w().is_zero() || middle.w().is_zero() {
+        return None;
+    }
+    let origin = Vector::from_homogeneous(near);
+    let direction = (Vector::<T, 3>::from_homogeneous(middle) - origin).normalized();
+    Some((origin, direction))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+
+    type Vector2 = Vector<f64, 2>;
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    const DEPTH_RANGES: [DepthRange; 2] = [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne];
+
+    fn viewport() -> Viewport<f64> {
+        Viewport::new(10.0, 20.0, 800.0, 600.0)
+    }
+
+    fn view() -> Matrix4 {
+        Matrix4::look_at_rh([1.0, 2.0, 10.0], [1.0, 2.0, 0.0], Vector3::unit_y())
+    }
+
+    fn projections(depth_range: DepthRange) -> [Matrix4; 5] {
+        let aspect = viewport().aspect();
+        [
+            Matrix4::perspective_fov_rh(1.0, aspect, 0.5, 50.0, depth_range),
+            Matrix4::perspective_fov_reverse_z_rh(1.0, aspect, 0.5, 50.0, depth_range),
+            Matrix4::perspective_fov_infinite_rh(1.0, aspect, 0.5, depth_range),
+            Matrix4::perspective_fov_infinite_reverse_z_rh(1.0, aspect, 0.5, depth_range),
+            Matrix4::orthographic_rh(-4.0, 4.0, -3.0, 3.0, 0.5, 50.0, depth_range),
+        ]
+    }
+
+    #[test]
+    fn project() {
+        for depth_range in DEPTH_RANGES {
+            let view_proj = view() * projections(depth_range)[0];
+            let p = super::project([1.0, 2.0, 0.0], view_proj, viewport(), depth_range).unwrap();
+            assert_approx_eq(p.xy().extend(0.0), Vector3::new(410.0, 320.0, 0.0), 1.0e-9);
+            assert!(p.z() > 0.0 && p.z() < 1.0);
+
+            // `+Y` in the world is up on the screen, that is towards smaller `y`.
+            let p = super::project([1.0, 3.0, 0.0], view_proj, viewport(), depth_range).unwrap();
+            assert!(p.y() < 320.0);
+
+            let near = super::project([1.0, 2.0, 9.5], view_proj, viewport(), depth_range);
+            assert!(near.unwrap().z().abs() < 1.0e-9);
+            let far = super::project([1.0, 2.0, -40.0], view_proj, viewport(), depth_range);
+            assert!((far.unwrap().z() - 1.0).abs() < 1.0e-9);
+
+            let behind = super::project([1.0, 2.0, 20.0], view_proj, viewport(), depth_range);
+            assert_eq!(behind, None);
+        }
+    }
+
+    #[test]
+    fn unproject() {
+        let points = [
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(-2.0, 3.0, 4.0),
+            Vector3::new(3.0, 0.0, -20.0),
+        ];
+        for depth_range in DEPTH_RANGES {
+            for projection in projections(depth_range) {
+                let view_proj = view() * projection;
+                let inv = view_proj.inverse().unwrap();
+                for point in points {
+                    let p = super::project(point, view_proj, viewport(), depth_range).unwrap();
+                    let q = super::unproject(p.xy(), p.z(), inv, viewport(), depth_range);
+                    assert_approx_eq(q.unwrap(), point, 1.0e-9);
+                }
+            }
+        }
+
+        let depth_range = DepthRange::ZeroToOne;
+        let view_proj = view() * projections(depth_range)[2];
+        let inv = view_proj.inverse().unwrap();
+        let q = super::unproject([410.0, 320.0], 1.0, inv, viewport(), depth_range);
+        assert_eq!(q, None);
+    }
+
+    #[test]
+    fn ray_from_screen() {
+        for depth_range in DEPTH_RANGES {
+            for projection in projections(depth_range) {
+                let view_proj = view() * projection;
+                let inv = view_proj.inverse().unwrap();
+
+                let center = Vector2::new(410.0, 320.0);
+                let (origin, direction) =
+                    super::ray_from_screen(center, inv, viewport(), depth_range).unwrap();
+                assert_approx_eq(origin, Vector3::new(1.0, 2.0, 9.5), 1.0e-9);
+                assert_approx_eq(direction, -Vector3::unit_z(), 1.0e-9);
+
+                let target = Vector3::new(-2.0, 3.0, 4.0);
+                let p = super::project(target, view_proj, viewport(), depth_range).unwrap();
+                let (origin, direction) =
+                    super::ray_from_screen(p.xy(), inv, viewport(), depth_range).unwrap();
+                let to_target = target - origin;
+                assert!(to_target.dot(direction) > 0.0);
+                assert_approx_eq(to_target.normalized(), direction, 1.0e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+// SSE2 versions of the hottest `Vector<f32, 4>` and `Matrix<f32, 4, 4>`
+// operations. They are separate methods next to the generic ones, which cannot
+// be specialized for a single element type. Component-wise operations and the
+// matrix product give exactly the results of the generic operators;
+// `simd_dot` and `simd_normalized` add the products in a different order and
+// may differ by a few ULPs.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod x86;
+
+// Without SSE2 the methods fall back to the generic operators, so code using
+// them builds on every target.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+mod fallback;
+
+#[cfg(test)]
+mod test {
+    use crate::math::matrix::*;
+    use crate::math::vector::*;
+    use num::Zero;
+    use rand::{Rng, SeedableRng};
+
+    type Vector4 = Vector<f32, 4>;
+    type Matrix4 = Matrix<f32, 4, 4>;
+
+    fn random_vectors() -> Vec<Vector4> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        (0..200)
+            .map(|_| [(); 4].map(|_| rng.gen_range(-100.0..100.0)).into())
+            .collect()
+    }
+
+    fn assert_ulps(a: f32, b: f32, ulps: u32, scale: f32) {
+        let tolerance = f32::EPSILON * scale * ulps as f32;
+        assert!((a - b).abs() <= tolerance, "{a} != {b}");
+    }
+
+    #[test]
+    fn component_wise_matches_scalar() {
+        for pair in random_vectors().windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(a.simd_add(b), a + b);
+            assert_eq!(a.simd_sub(b), a - b);
+            assert_eq!(a.simd_mul(b), a * b);
+            assert_eq!(a.simd_div(b), a / b);
+        }
+    }
+
+    #[test]
+    fn dot_matches_scalar() {
+        for pair in random_vectors().windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let scale = (a * b).map(|e| e.abs()).fold(0.0, |acc, e| acc + e);
+            assert_ulps(a.simd_dot(b), a.dot(b), 2, scale);
+        }
+        let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.simd_dot(v), 30.0);
+    }
+
+    #[test]
+    fn normalized_matches_scalar() {
+        for a in random_vectors() {
+            let (n, expected) = (a.simd_normalized(), a.normalized());
+            for i in 0..4 {
+                assert_ulps(n[i], expected[i], 2, 1.0);
+            }
+        }
+        assert_eq!(Vector4::zero().simd_normalized(), Vector4::zero());
+        assert_eq!(
+            Vector4::new(0.0, 3.0, 0.0, 4.0).simd_normalized(),
+            Vector4::new(0.0, 0.6, 0.0, 0.8)
+        );
+    }
+
+    #[test]
+    fn mul_matrix_matches_scalar() {
+        let vs = random_vectors();
+        for chunk in vs.chunks_exact(8) {
+            let a = Matrix4::from([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            let b = Matrix4::from([chunk[4], chunk[5], chunk[6], chunk[7]]);
+            assert_eq!(a.simd_mul(b), a * b);
+        }
+        let m: Matrix4 = [[1.0, 2.0, 3.0, 4.0]; 4].to_matrix();
+        assert_eq!(m.simd_mul(Matrix4::identity()), m);
+        assert_eq!(Matrix4::identity().simd_mul(m), m);
+    }
+}
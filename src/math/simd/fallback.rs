@@ -0,0 +1,41 @@
+use crate::math::matrix::*;
+use crate::math::vector::*;
+
+impl Vector<f32, 4> {
+    #[inline]
+    pub fn simd_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    #[inline]
+    pub fn simd_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    #[inline]
+    pub fn simd_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    #[inline]
+    pub fn simd_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    #[inline]
+    pub fn simd_dot(&self, rhs: Self) -> f32 {
+        self.dot(rhs)
+    }
+
+    #[inline]
+    pub fn simd_normalized(&self) -> Self {
+        self.normalized()
+    }
+}
+
+impl Matrix<f32, 4, 4> {
+    #[inline]
+    pub fn simd_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+}
@@ -0,0 +1,126 @@
+use crate::math::matrix::*;
+use crate::math::vector::*;
+use std::arch::x86_64::*;
+
+#[inline]
+fn load(v: &Vector<f32, 4>) -> __m128 {
+    // SAFETY: `v` derefs to `[f32; 4]`, which is exactly the 16 bytes read,
+    // and the unaligned load has no alignment requirement.
+    unsafe { _mm_loadu_ps(v.as_ptr()) }
+}
+
+#[inline]
+fn store(v: __m128) -> Vector<f32, 4> {
+    let mut result = [0.0_f32; 4];
+    // SAFETY: `result` is exactly the 16 bytes written, and the unaligned
+    // store has no alignment requirement.
+    unsafe { _mm_storeu_ps(result.as_mut_ptr(), v) };
+    result.into()
+}
+
+// Leaves `(v0 + v1) + (v2 + v3)` in the lowest lane.
+#[inline]
+fn horizontal_sum(v: __m128) -> __m128 {
+    // SAFETY: this module is only compiled when SSE2 is enabled.
+    unsafe {
+        let swapped = _mm_shuffle_ps::<0b10_11_00_01>(v, v);
+        let sums = _mm_add_ps(v, swapped);
+        _mm_add_ss(sums, _mm_movehl_ps(swapped, sums))
+    }
+}
+
+impl Vector<f32, 4> {
+    #[inline]
+    pub fn simd_add(self, rhs: Self) -> Self {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        store(unsafe { _mm_add_ps(load(&self), load(&rhs)) })
+    }
+
+    #[inline]
+    pub fn simd_sub(self, rhs: Self) -> Self {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        store(unsafe { _mm_sub_ps(load(&self), load(&rhs)) })
+    }
+
+    #[inline]
+    pub fn simd_mul(self, rhs: Self) -> Self {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        store(unsafe { _mm_mul_ps(load(&self), load(&rhs)) })
+    }
+
+    #[inline]
+    pub fn simd_div(self, rhs: Self) -> Self {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        store(unsafe { _mm_div_ps(load(&self), load(&rhs)) })
+    }
+
+    #[inline]
+    pub fn simd_dot(&self, rhs: Self) -> f32 {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        unsafe { _mm_cvtss_f32(horizontal_sum(_mm_mul_ps(load(self), load(&rhs)))) }
+    }
+
+    #[inline]
+    pub fn simd_normalized(&self) -> Self {
+        // SAFETY: this module is only compiled when SSE2 is enabled.
+        unsafe {
+            let x = load(self);
+            let length_squared = horizontal_sum(_mm_mul_ps(x, x));
+            if _mm_cvtss_f32(length_squared) == 0.0 {
+                return Self::default();
+            }
+            let length = _mm_sqrt_ps(_mm_shuffle_ps::<0>(length_squared, length_squared));
+            store(_mm_div_ps(x, length))
+        }
+    }
+}
+
+impl Matrix<f32, 4, 4> {
+    // Each row of the result accumulates `self[i][k] * rhs[k]` from zero in
+    // the same order as `Vector::dot` on a row and a column, so the sums match
+    // `self * rhs` exactly.
+    #[cfg(not(target_feature = "avx"))]
+    #[inline]
+    pub fn simd_mul(self, rhs: Self) -> Self {
+        let rows = rhs.elements.map(|row| load(&row));
+        let mut m = Self::default();
+        for i in 0..4 {
+            // SAFETY: this module is only compiled when SSE2 is enabled.
+            let acc = unsafe {
+                let mut acc = _mm_setzero_ps();
+                for (k, &row) in rows.iter().enumerate() {
+                    acc = _mm_add_ps(acc, _mm_mul_ps(_mm_set1_ps(self[i][k]), row));
+                }
+                acc
+            };
+            m.elements[i] = store(acc);
+        }
+        m
+    }
+
+    // Computes two rows of the result per 256-bit register, with `rhs`
+    // repeated in both halves and each element of the two rows of `self`
+    // broadcast within its half.
+    #[cfg(target_feature = "avx")]
+    #[inline]
+    pub fn simd_mul(self, rhs: Self) -> Self {
+        let mut m = Self::default();
+        // SAFETY: AVX is enabled by the `cfg` above. `Matrix` and `Vector` are
+        // `repr(C)` wrappers around arrays, so rows `i` and `i + 1` are eight
+        // contiguous `f32`s in both `self` and `m`, and the unaligned loads
+        // and stores have no alignment requirement.
+        unsafe {
+            let rows = rhs.elements.map(|row| _mm256_broadcast_ps(&load(&row)));
+            for i in [0, 2] {
+                let a = _mm256_loadu_ps(self.elements[i..].as_ptr().cast());
+                let mut acc = _mm256_setzero_ps();
+                acc = _mm256_add_ps(acc, _mm256_mul_ps(_mm256_shuffle_ps::<0x00>(a, a), rows[0]));
+                acc = _mm256_add_ps(acc, _mm256_mul_ps(_mm256_shuffle_ps::<0x55>(a, a), rows[1]));
+                acc = _mm256_add_ps(acc, _mm256_mul_ps(_mm256_shuffle_ps::<0xAA>(a, a), rows[2]));
+                acc = _mm256_add_ps(acc, _mm256_mul_ps(_mm256_shuffle_ps::<0xFF>(a, a), rows[3]));
+                _mm256_storeu_ps(m.elements[i..].as_mut_ptr().cast(), acc);
+            }
+        }
+        m
+    }
+}
@@ -0,0 +1,472 @@
+use crate::math::matrix::*;
+use crate::math::normal::Normal;
+use crate::math::point::Point;
+use crate::math::quaternion::Quaternion;
+use crate::math::vector::*;
+use num::{Float, Zero};
+use std::ops::Mul;
+
+// Transforms compose like the row-vector matrices they convert to: `a * b`
+// applies `a` first and then `b`, so `(a * b).to_matrix()` equals
+// `a.to_matrix() * b.to_matrix()`. Points are translated, vectors are not,
+// and normals are transformed by the inverse transpose of the linear part so
+// that they stay perpendicular to the transformed surface.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry3<T: MatrixElement + Float> {
+    pub rotation: Quaternion<T>,
+    pub translation: Vector<T, 3>,
+}
+
+impl<T: MatrixElement + Float> Default for Isometry3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: MatrixElement + Float> Isometry3<T> {
+    pub fn new(rotation: Quaternion<T>, translation: impl VectorLike<T, 3>) -> Self {
+        Self {
+            rotation,
+            translation: translation.to_vector(),
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Quaternion::identity(), Vector::<T, 3>::zero())
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self::new(rotation, Vector::<T, 3>::zero())
+    }
+
+    pub fn from_translation(translation: impl VectorLike<T, 3>) -> Self {
+        Self::new(Quaternion::identity(), translation)
+    }
+
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        Self::new(rotation, -rotation.rotate_vector(self.translation))
+    }
+
+    pub fn transform_point(&self, p: Point<T, 3>) -> Point<T, 3> {
+        Point::from(self.rotation.rotate_vector(p.coords())) + self.translation
+    }
+
+    pub fn transform_vector(&self, v: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation.rotate_vector(v)
+    }
+
+    pub fn transform_normal(&self, n: Normal<T, 3>) -> Normal<T, 3> {
+        self.rotation.rotate_vector(n.vector()).into()
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        let mut m = self.rotation.to_matrix4();
+        for i in 0..3 {
+            m[3][i] = self.translation[i];
+        }
+        m
+    }
+}
+
+impl<T: MatrixElement + Float> Mul<Self> for Isometry3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            rhs.rotation * self.rotation,
+            rhs.transform_vector(self.translation) + rhs.translation,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Similarity3<T: MatrixElement + Float> {
+    pub rotation: Quaternion<T>,
+    pub translation: Vector<T, 3>,
+    pub scale: T,
+}
+
+impl<T: MatrixElement + Float> Default for Similarity3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: MatrixElement + Float> Similarity3<T> {
+    pub fn new(rotation: Quaternion<T>, translation: impl VectorLike<T, 3>, scale: T) -> Self {
+        Self {
+            rotation,
+            translation: translation.to_vector(),
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Isometry3::identity().into()
+    }
+
+    pub fn from_scale(scale: T) -> Self {
+        Self::new(Quaternion::identity(), Vector::<T, 3>::zero(), scale)
+    }
+
+    pub fn isometry(&self) -> Isometry3<T> {
+        Isometry3::new(self.rotation, self.translation)
+    }
+
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let scale = T::one() / self.scale;
+        Self::new(
+            rotation,
+            -rotation.rotate_vector(self.translation) * scale,
+            scale,
+        )
+    }
+
+    pub fn transform_point(&self, p: Point<T, 3>) -> Point<T, 3> {
+        Point::from(self.transform_vector(p.coords())) + self.translation
+    }
+
+    pub fn transform_vector(&self, v: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation.rotate_vector(v) * self.scale
+    }
+
+    // A uniform scale does not change the direction of a normal, so normals
+    // are only rotated and keep their length.
+    pub fn transform_normal(&self, n: Normal<T, 3>) -> Normal<T, 3> {
+        self.rotation.rotate_vector(n.vector()).into()
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        let mut m = self.rotation.to_matrix4();
+        for r in 0..3 {
+            m[r] *= self.scale;
+            m[3][r] = self.translation[r];
+        }
+        m
+    }
+}
+
+impl<T: MatrixElement + Float> Mul<Self> for Similarity3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            rhs.rotation * self.rotation,
+            rhs.transform_vector(self.translation) + rhs.translation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl<T: MatrixElement + Float> From<Isometry3<T>> for Similarity3<T> {
+    fn from(isometry: Isometry3<T>) -> Self {
+        Self::new(isometry.rotation, isometry.translation, T::one())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine3<T: MatrixElement + Float> {
+    pub matrix: Matrix<T, 4, 4>,
+}
+
+impl<T: MatrixElement + Float> Default for Affine3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: MatrixElement + Float> Affine3<T> {
+    pub fn new(matrix: Matrix<T, 4, 4>) -> Self {
+        Self { matrix }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Matrix::identity())
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        self.matrix.inverse().map(Self::new)
+    }
+
+    pub fn transform_point(&self, p: Point<T, 3>) -> Point<T, 3> {
+        self.matrix.transform_point(p.coords()).into()
+    }
+
+    pub fn transform_vector(&self, v: Vector<T, 3>) -> Vector<T, 3> {
+        self.matrix.transform_vector(v)
+    }
+
+    // The rows of the inverse transpose are the cross products of the rows
+    // of the linear part divided by its determinant. Only the sign of the
+    // determinant is kept and the result is renormalized, so singular
+    // matrices still give a usable direction.
+    pub fn transform_normal(&self, n: Normal<T, 3>) -> Normal<T, 3> {
+        let (r0, r1, r2) = (
+            self.matrix[0].xyz(),
+            self.matrix[1].xyz(),
+            self.matrix[2].xyz(),
+        );
+        let cofactor: Matrix<T, 3, 3> = [r1.cross(r2), r2.cross(r0), r0.cross(r1)].into();
+        let sign = r0.dot(r1.cross(r2)).signum();
+        Normal::from(n.vector() * cofactor * sign).normalized()
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        self.matrix
+    }
+}
+
+impl<T: MatrixElement + Float> Mul<Self> for Affine3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.matrix * rhs.matrix)
+    }
+}
+
+impl<T: MatrixElement + Float> From<Isometry3<T>> for Affine3<T> {
+    fn from(isometry: Isometry3<T>) -> Self {
+        Self::new(isometry.to_matrix())
+    }
+}
+
+impl<T: MatrixElement + Float> From<Similarity3<T>> for Affine3<T> {
+    fn from(similarity: Similarity3<T>) -> Self {
+        Self::new(similarity.to_matrix())
+    }
+}
+
+impl<T: MatrixElement + Float> From<Isometry3<T>> for Matrix<T, 4, 4> {
+    fn from(isometry: Isometry3<T>) -> Self {
+        isometry.to_matrix()
+    }
+}
+
+impl<T: MatrixElement + Float> From<Similarity3<T>> for Matrix<T, 4, 4> {
+    fn from(similarity: Similarity3<T>) -> Self {
+        similarity.to_matrix()
+    }
+}
+
+impl<T: MatrixElement + Float> From<Affine3<T>> for Matrix<T, 4, 4> {
+    fn from(affine: Affine3<T>) -> Self {
+        affine.to_matrix()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Deg;
+    use crate::math::approx::assert_approx_eq;
+
+    type Point3 = Point<f64, 3>;
+    type Normal3 = Normal<f64, 3>;
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    fn isometry_a() -> Isometry3<f64> {
+        Isometry3::new(
+            Quaternion::from_axis_angle([1.0, 2.0, -0.5], 0.8),
+            [1.0, -2.0, 3.0],
+        )
+    }
+
+    fn isometry_b() -> Isometry3<f64> {
+        Isometry3::new(
+            Quaternion::from_axis_angle([0.0, 1.0, 1.0], -1.9),
+            [0.5, 4.0, -1.0],
+        )
+    }
+
+    #[test]
+    fn isometry_transform() {
+        let iso = Isometry3::new(
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(90.0)),
+            [1.0, 2.0, 3.0],
+        );
+        let p = Point3::new(1.0, 0.0, 0.0);
+        let v = Vector3::unit_x();
+        assert_approx_eq(iso.transform_point(p), [1.0, 3.0, 3.0], 1.0e-10);
+        assert_approx_eq(iso.transform_vector(v), Vector3::unit_y(), 1.0e-10);
+        assert_approx_eq(iso.transform_normal(v.into()), Vector3::unit_y(), 1.0e-10);
+
+        let iso = isometry_a();
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        let v = p.coords();
+        let m = iso.to_matrix();
+        assert_approx_eq(
+            iso.transform_point(p),
+            m.transform_point(p.coords()),
+            1.0e-10,
+        );
+        assert_approx_eq(iso.transform_vector(v), m.transform_vector(v), 1.0e-10);
+    }
+
+    #[test]
+    fn isometry_mul() {
+        let (a, b) = (isometry_a(), isometry_b());
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        assert_approx_eq(
+            (a * b).transform_point(p),
+            b.transform_point(a.transform_point(p)),
+            1.0e-10,
+        );
+        assert_approx_eq((a * b).to_matrix(), a.to_matrix() * b.to_matrix(), 1.0e-10);
+    }
+
+    #[test]
+    fn isometry_inverse() {
+        let iso = isometry_a();
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        assert_approx_eq(
+            iso.inverse().transform_point(iso.transform_point(p)),
+            p,
+            1.0e-10,
+        );
+        assert_approx_eq(
+            (iso * iso.inverse()).to_matrix(),
+            Matrix4::identity(),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            iso.inverse().to_matrix(),
+            iso.to_matrix().inverse().unwrap(),
+            1.0e-10,
+        );
+    }
+
+    #[test]
+    fn similarity_transform() {
+        let sim = Similarity3::new(
+            Quaternion::from_axis_angle(Vector3::unit_z(), Deg(90.0)),
+            [1.0, 2.0, 3.0],
+            2.0,
+        );
+        let p = Point3::new(1.0, 0.0, 0.0);
+        let v = Vector3::unit_x();
+        assert_approx_eq(sim.transform_point(p), [1.0, 4.0, 3.0], 1.0e-10);
+        assert_approx_eq(sim.transform_vector(v), [0.0, 2.0, 0.0], 1.0e-10);
+        assert_approx_eq(sim.transform_normal(v.into()), [0.0, 1.0, 0.0], 1.0e-10);
+
+        let m = sim.to_matrix();
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        let v = p.coords();
+        assert_approx_eq(
+            sim.transform_point(p),
+            m.transform_point(p.coords()),
+            1.0e-10,
+        );
+        assert_approx_eq(sim.transform_vector(v), m.transform_vector(v), 1.0e-10);
+    }
+
+    #[test]
+    fn similarity_mul_and_inverse() {
+        let a = Similarity3::new(isometry_a().rotation, isometry_a().translation, 0.5);
+        let b = Similarity3::new(isometry_b().rotation, isometry_b().translation, 3.0);
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        assert_approx_eq(
+            (a * b).transform_point(p),
+            b.transform_point(a.transform_point(p)),
+            1.0e-10,
+        );
+        assert_approx_eq((a * b).to_matrix(), a.to_matrix() * b.to_matrix(), 1.0e-10);
+        assert_approx_eq(
+            a.inverse().transform_point(a.transform_point(p)),
+            p,
+            1.0e-10,
+        );
+        assert_approx_eq(
+            a.inverse().to_matrix(),
+            a.to_matrix().inverse().unwrap(),
+            1.0e-10,
+        );
+        assert_eq!(Similarity3::from(isometry_a()).isometry(), isometry_a());
+    }
+
+    #[test]
+    fn affine() {
+        let m = Matrix4::scaling([1.0, 2.0, 3.0])
+            * Matrix4::shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0)
+            * Matrix4::translation([1.0, 0.0, -1.0]);
+        let a = Affine3::new(m);
+        let b: Affine3<f64> = isometry_b().into();
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        let v = p.coords();
+        assert_approx_eq(a.transform_point(p), m.transform_point(p.coords()), 1.0e-10);
+        assert_approx_eq(a.transform_vector(v), m.transform_vector(v), 1.0e-10);
+        assert_approx_eq(
+            (a * b).transform_point(p),
+            b.transform_point(a.transform_point(p)),
+            1.0e-10,
+        );
+        assert_approx_eq(
+            a.inverse().unwrap().transform_point(a.transform_point(p)),
+            p,
+            1.0e-10,
+        );
+        assert_eq!(Affine3::new(Matrix4::zero()).inverse(), None);
+        assert_eq!(Matrix4::from(a), m);
+    }
+
+    #[test]
+    fn point_and_vector_semantics() {
+        let iso = Isometry3::from_translation([1.0, 2.0, 3.0]);
+        let sim = Similarity3::from(iso);
+        let affine = Affine3::from(iso);
+        let p = Point3::new(1.0, 1.0, 1.0);
+        let v = Vector3::new(1.0, 1.0, 1.0);
+        let n = Normal3::new(0.0, 1.0, 0.0);
+        assert_approx_eq(iso.transform_point(p), [2.0, 3.0, 4.0], 1.0e-10);
+        assert_approx_eq(sim.transform_point(p), [2.0, 3.0, 4.0], 1.0e-10);
+        assert_approx_eq(affine.transform_point(p), [2.0, 3.0, 4.0], 1.0e-10);
+        assert_approx_eq(iso.transform_vector(v), v, 1.0e-10);
+        assert_approx_eq(sim.transform_vector(v), v, 1.0e-10);
+        assert_approx_eq(affine.transform_vector(v), v, 1.0e-10);
+        assert_approx_eq(iso.transform_normal(n), n, 1.0e-10);
+        assert_approx_eq(sim.transform_normal(n), n, 1.0e-10);
+        assert_approx_eq(affine.transform_normal(n), n, 1.0e-10);
+    }
+
+    #[test]
+    fn affine_transform_normal() {
+        // the plane x + y = 0 becomes x + y / 2 = 0 after stretching y
+        let a =
+            Affine3::new(Matrix4::scaling([1.0, 2.0, 1.0]) * Matrix4::translation([0.0, 0.0, 5.0]));
+        let n = Normal3::new(1.0, 1.0, 0.0).normalized();
+        let expected = Vector3::new(2.0, 1.0, 0.0).normalized();
+        assert_approx_eq(a.transform_normal(n), expected, 1.0e-10);
+
+        let tangent = Vector3::new(1.0, -1.0, 0.0);
+        assert!(a.transform_normal(n).dot(a.transform_vector(tangent)).abs() < 1.0e-10);
+
+        let mirror = Affine3::new(Matrix4::scaling([-1.0, 1.0, 1.0]));
+        assert_approx_eq(
+            mirror.transform_normal(Normal3::new(1.0, 0.0, 0.0)),
+            [-1.0, 0.0, 0.0],
+            1.0e-10,
+        );
+
+        let m = Matrix4::rotation_axis_angle([1.0, 2.0, 3.0], 0.7)
+            * Matrix4::shear(0.3, 0.0, 0.0, 0.2, 0.0, 0.0);
+        let a = Affine3::new(m);
+        let inverse_transpose = m.inverse().unwrap().transpose();
+        let expected = inverse_transpose.transform_vector(n.vector()).normalized();
+        assert_approx_eq(a.transform_normal(n), expected, 1.0e-10);
+    }
+
+    #[test]
+    fn conversions() {
+        let iso = isometry_a();
+        let p = Point3::new(-1.0, 0.5, 2.0);
+        let sim: Similarity3<f64> = iso.into();
+        let affine: Affine3<f64> = sim.into();
+        assert_approx_eq(sim.transform_point(p), iso.transform_point(p), 1.0e-10);
+        assert_approx_eq(affine.transform_point(p), iso.transform_point(p), 1.0e-10);
+        assert_approx_eq(Matrix4::from(iso), Matrix4::from(sim), 1.0e-10);
+        assert_eq!(Isometry3::<f64>::default(), Isometry3::identity());
+    }
+}
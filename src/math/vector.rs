@@ -1,6 +1,8 @@
 mod traits;
 
-use num::{Float, One, Zero};
+use crate::math::angle::Rad;
+use num::traits::AsPrimitive;
+use num::{Bounded, Float, NumCast, One, Signed, ToPrimitive, Zero};
 pub use std::fmt::{self, Display, Formatter};
 use std::ops::*;
 pub use traits::*;
@@ -68,6 +70,21 @@ impl<T: VectorElement> Vector<T, 2> {
             elements: [T::zero(), T::one()],
         }
     }
+
+    // The z component of the 3D cross product, positive when `other` is
+    // counterclockwise from `self`.
+    pub fn perp_dot(&self, other: impl VectorLike<T, 2>) -> T {
+        *self.get(0) * *other.get(1) - *self.get(1) * *other.get(0)
+    }
+}
+
+impl<T: VectorElement + Neg<Output = T>> Vector<T, 2> {
+    // `self` rotated 90 degrees counterclockwise.
+    pub fn perp(&self) -> Self {
+        Self {
+            elements: [-self.elements[1], self.elements[0]],
+        }
+    }
 }
 
 impl<T: VectorElement> Vector<T, 3> {
@@ -95,16 +112,32 @@ impl<T: VectorElement> Vector<T, 3> {
         }
     }
 
+    #[deprecated(note = "misspelled, use `cross`")]
     pub fn corss(&self, other: impl VectorLike<T, 3>) -> Self {
+        self.cross(other)
+    }
+
+    pub fn cross(&self, other: impl VectorLike<T, 3>) -> Self {
         Self {
             elements: [
                 *self.get(1) * *other.get(2) - *self.get(2) * *other.get(1),
-                *self.get(2) * *other.get(1) - *self.get(1) * *other.get(2),
+                *self.get(2) * *other.get(0) - *self.get(0) * *other.get(2),
                 *self.get(0) * *other.get(1) - *self.get(1) * *other.get(0),
             ],
         }
     }
 
+    // `self . (b x c)`, the signed volume of the parallelepiped spanned by the
+    // three vectors.
+    pub fn scalar_triple(&self, b: impl VectorLike<T, 3>, c: impl VectorLike<T, 3>) -> T {
+        self.dot(b.to_vector().cross(c))
+    }
+
+    // `self x (b x c)`
+    pub fn vector_triple(&self, b: impl VectorLike<T, 3>, c: impl VectorLike<T, 3>) -> Self {
+        self.cross(b.to_vector().cross(c))
+    }
+
     pub fn reflect(&self, normal: impl VectorLike<T, 3>) -> Self {
         let normal = normal.to_vector();
         let two = T::one() + T::one();
@@ -182,6 +215,84 @@ impl<T: VectorElement, const DIMENSION: usize> Vector<T, DIMENSION> {
         }
         result
     }
+
+    pub fn map<U: VectorElement>(self, f: impl FnMut(T) -> U) -> Vector<U, DIMENSION> {
+        Vector {
+            elements: self.elements.map(f),
+        }
+    }
+
+    pub fn zip_map<U, R>(
+        self,
+        other: impl VectorLike<U, DIMENSION>,
+        mut f: impl FnMut(T, U) -> R,
+    ) -> Vector<R, DIMENSION>
+    where
+        U: VectorElement,
+        R: VectorElement,
+    {
+        let mut elements = [R::default(); DIMENSION];
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element = f(self.elements[i], *other.get(i));
+        }
+        Vector { elements }
+    }
+
+    pub fn fold<A>(self, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.elements.into_iter().fold(init, f)
+    }
+
+    pub fn for_each(self, f: impl FnMut(T)) {
+        self.elements.into_iter().for_each(f);
+    }
+
+    pub fn extend<const M: usize>(self, value: T) -> Vector<T, M> {
+        const {
+            assert!(M == DIMENSION + 1);
+        }
+        let mut elements = [value; M];
+        elements[..DIMENSION].copy_from_slice(&self.elements);
+        Vector { elements }
+    }
+
+    pub fn truncate<const M: usize>(self) -> Vector<T, M> {
+        const {
+            assert!(M + 1 == DIMENSION);
+        }
+        let mut elements = [T::default(); M];
+        elements.copy_from_slice(&self.elements[..M]);
+        Vector { elements }
+    }
+
+    pub fn to_homogeneous<const M: usize>(self) -> Vector<T, M> {
+        self.extend(T::one())
+    }
+
+    pub fn concat<const M: usize, const K: usize>(
+        self,
+        other: impl VectorLike<T, M>,
+    ) -> Vector<T, K> {
+        const {
+            assert!(K == DIMENSION + M);
+        }
+        let mut elements = [T::default(); K];
+        elements[..DIMENSION].copy_from_slice(&self.elements);
+        for (i, element) in elements[DIMENSION..].iter_mut().enumerate() {
+            *element = *other.get(i);
+        }
+        Vector { elements }
+    }
+
+    pub fn split_at<const M: usize, const K: usize>(self) -> (Vector<T, M>, Vector<T, K>) {
+        const {
+            assert!(M + K == DIMENSION);
+        }
+        let mut head = [T::default(); M];
+        let mut tail = [T::default(); K];
+        head.copy_from_slice(&self.elements[..M]);
+        tail.copy_from_slice(&self.elements[M..]);
+        (Vector { elements: head }, Vector { elements: tail })
+    }
 }
 
 impl<T, const DIMENSION: usize> Vector<T, DIMENSION>
@@ -214,8 +325,26 @@ where
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
+
+    // Divides by the last component of `v`, which must not be zero.
+    pub fn from_homogeneous<const M: usize>(v: Vector<T, M>) -> Self {
+        const {
+            assert!(M == DIMENSION + 1);
+        }
+        let w = v.elements[DIMENSION];
+        let mut elements = [T::default(); DIMENSION];
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element = v.elements[i] / w;
+        }
+        Self { elements }
+    }
 }
 
+include!("./vector/component.rs");
+include!("./vector/geometric.rs");
+include!("./vector/cast.rs");
+include!("./vector/iter.rs");
+
 pub trait ToVector<T: VectorElement, const DIMENSION: usize> {
     fn to_vector(self) -> Vector<T, DIMENSION>;
 }
@@ -333,6 +462,72 @@ mod test {
         assert_eq!(v1.dot(v2), 73);
     }
 
+    #[test]
+    fn cross() {
+        let v1 = Vector3::new(1, 2, 3);
+        let v2 = Vector3::new(4, 5, 6);
+        assert_eq!(v1.cross(v2), Vector3::new(-3, 6, -3));
+        assert_eq!(v2.cross(v1), Vector3::new(3, -6, 3));
+
+        let v1 = Vector3::new(2, -1, 5);
+        let v2 = Vector3::new(-3, 4, 1);
+        assert_eq!(v1.cross(v2), Vector3::new(-21, -17, 5));
+        assert_eq!(v1.cross(v2).dot(v1), 0);
+        assert_eq!(v1.cross(v2).dot(v2), 0);
+        assert_eq!(v1.cross(v1), Vector3::zero());
+    }
+
+    #[test]
+    fn cross_basis() {
+        let (x, y, z) = (
+            Vector3::<i32>::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_z(),
+        );
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(z), x);
+        assert_eq!(z.cross(x), y);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(z.cross(y), -x);
+        assert_eq!(x.cross(z), -y);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn corss() {
+        let v1 = Vector3::new(2, -1, 5);
+        let v2 = Vector3::new(-3, 4, 1);
+        assert_eq!(v1.corss(v2), v1.cross(v2));
+    }
+
+    #[test]
+    fn triple_products() {
+        let a = Vector3::new(1, 2, 3);
+        let b = Vector3::new(-2, 0, 4);
+        let c = Vector3::new(3, -1, 1);
+        assert_eq!(a.scalar_triple(b, c), 38);
+        assert_eq!(b.scalar_triple(c, a), 38);
+        assert_eq!(b.scalar_triple(a, c), -38);
+        assert_eq!(
+            Vector3::<i32>::unit_x().scalar_triple(Vector3::unit_y(), Vector3::unit_z()),
+            1
+        );
+
+        // `a x (b x c) = b (a . c) - c (a . b)`
+        assert_eq!(a.vector_triple(b, c), b * a.dot(c) - c * a.dot(b));
+    }
+
+    #[test]
+    fn perp() {
+        let v = Vector2::new(3, 1);
+        assert_eq!(v.perp(), Vector2::new(-1, 3));
+        assert_eq!(v.perp().dot(v), 0);
+        assert_eq!(Vector2::<i32>::unit_x().perp_dot(Vector2::unit_y()), 1);
+        assert_eq!(Vector2::<i32>::unit_y().perp_dot(Vector2::unit_x()), -1);
+        assert_eq!(v.perp_dot([2, 5]), 13);
+        assert_eq!(v.perp_dot(v.perp()), v.length_squared());
+    }
+
     #[test]
     fn reflect() {
         let l = Vector3::new(1.0, -1.0, 0.0);
@@ -346,4 +541,73 @@ mod test {
         let n = Vector3::unit_x();
         assert_eq!(l.reflect(n), Vector3::new(-1.0, -1.0, 5.0_f64));
     }
+
+    #[test]
+    fn map() {
+        let v = Vector3::new(1, 2, 3);
+        assert_eq!(v.map(|e| e * 2), Vector3::new(2, 4, 6));
+        assert_eq!(v.map(|e| e as f64 / 2.0), Vector3::new(0.5, 1.0, 1.5));
+    }
+
+    #[test]
+    fn zip_map() {
+        let v1 = Vector3::new(1, 2, 3);
+        let v2 = Vector3::new(4, 5, 6);
+        assert_eq!(v1.zip_map(v2, |a, b| a * 10 + b), Vector3::new(14, 25, 36));
+        assert_eq!(v1.zip_map([1, 1, 2], |a, b| a - b), Vector3::new(0, 1, 1));
+    }
+
+    #[test]
+    fn fold() {
+        let v = Vector4::new(1, 2, 3, 4);
+        assert_eq!(v.fold(0, |acc, e| acc + e), 10);
+        assert_eq!(v.fold(1, |acc, e| acc * e), 24);
+    }
+
+    #[test]
+    fn for_each() {
+        let v = Vector3::new(1, 2, 3);
+        let mut visited = Vec::new();
+        v.for_each(|e| visited.push(e));
+        assert_eq!(visited, [1, 2, 3]);
+    }
+
+    #[test]
+    fn extend_truncate() {
+        let v = Vector3::new(1, 2, 3);
+        let v4: Vector4<i32> = v.extend(4);
+        assert_eq!(v4, Vector4::new(1, 2, 3, 4));
+        assert_eq!(v4.truncate::<3>(), v);
+        assert_eq!(Vector1::new(5).extend::<2>(6), Vector2::new(5, 6));
+        assert_eq!(Vector::<i32, 6>::from([1; 6]).extend::<7>(2)[6], 2);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let h: Vector4<f64> = v.to_homogeneous();
+        assert_eq!(h, Vector4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(Vector3::from_homogeneous(h), v);
+        assert_eq!(
+            Vector3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 2.0)),
+            v
+        );
+        assert_eq!(
+            Vector2::from_homogeneous(Vector3::new(3.0, 6.0, 3.0)),
+            Vector2::new(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn concat_split_at() {
+        let v: Vector<i32, 5> = Vector2::new(1, 2).concat([3, 4, 5]);
+        assert_eq!(v, [1, 2, 3, 4, 5].into());
+        let (a, b): (Vector2<i32>, Vector3<i32>) = v.split_at();
+        assert_eq!(a, Vector2::new(1, 2));
+        assert_eq!(b, Vector3::new(3, 4, 5));
+
+        let (a, b) = Vector4::new(1, 2, 3, 4).split_at::<0, 4>();
+        assert_eq!(a, Vector::<i32, 0>::from([]));
+        assert_eq!(b, Vector4::new(1, 2, 3, 4));
+    }
 }
@@ -0,0 +1,144 @@
+impl<T: VectorElement, const DIMENSION: usize> Vector<T, DIMENSION> {
+    // Converts every element with `as`, so floats are truncated and saturated
+    // into integers and integers wrap.
+    pub fn cast<U>(self) -> Vector<U, DIMENSION>
+    where
+        T: AsPrimitive<U>,
+        U: VectorElement + 'static,
+    {
+        self.map(|e| e.as_())
+    }
+
+    // Returns `None` if any element is out of the range of `U`, or is NaN or
+    // infinite while `U` is an integer.
+    pub fn try_cast<U>(self) -> Option<Vector<U, DIMENSION>>
+    where
+        T: ToPrimitive,
+        U: VectorElement + NumCast,
+    {
+        let mut elements = [U::default(); DIMENSION];
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element = cast_element(self.elements[i])?;
+        }
+        Some(Vector { elements })
+    }
+
+    // Clamps elements that are out of the range of `U` to its bounds, and
+    // turns NaN into zero.
+    pub fn saturating_cast<U>(self) -> Vector<U, DIMENSION>
+    where
+        T: ToPrimitive + PartialOrd,
+        U: VectorElement + NumCast + Bounded,
+    {
+        self.map(|e| {
+            cast_element(e).unwrap_or_else(|| {
+                if e < T::zero() {
+                    U::min_value()
+                } else if e > T::zero() {
+                    U::max_value()
+                } else {
+                    U::zero()
+                }
+            })
+        })
+    }
+}
+
+// `NumCast` turns finite floats that are out of the range of a float `U` into
+// infinities instead of failing, so those are rejected here as well.
+pub(crate) fn cast_element<T: ToPrimitive, U: NumCast>(e: T) -> Option<U> {
+    let finite = |f: Option<f64>| f.is_some_and(f64::is_finite);
+    let was_finite = finite(e.to_f64());
+    let u = U::from(e)?;
+    if was_finite && !finite(u.to_f64()) {
+        None
+    } else {
+        Some(u)
+    }
+}
+
+macro_rules! impl_from_widening {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl<const DIMENSION: usize> From<Vector<$from, DIMENSION>> for Vector<$to, DIMENSION> {
+                fn from(v: Vector<$from, DIMENSION>) -> Self {
+                    v.map(<$to as From<$from>>::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_widening!(i8 => i16, i32, i64, i128, f32, f64);
+impl_from_widening!(i16 => i32, i64, i128, f32, f64);
+impl_from_widening!(i32 => i64, i128, f64);
+impl_from_widening!(i64 => i128);
+impl_from_widening!(u8 => u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+impl_from_widening!(u16 => u32, u64, u128, i32, i64, i128, f32, f64);
+impl_from_widening!(u32 => u64, u128, i64, i128, f64);
+impl_from_widening!(u64 => u128, i128);
+impl_from_widening!(f32 => f64);
+
+#[cfg(test)]
+mod cast_test {
+    use super::*;
+    type Vector2<T> = Vector<T, 2>;
+    type Vector3<T> = Vector<T, 3>;
+
+    #[test]
+    fn cast() {
+        let v = Vector3::new(1.5_f64, -2.7, 1.0e10);
+        assert_eq!(v.cast::<f32>(), Vector3::new(1.5_f32, -2.7, 1.0e10));
+        assert_eq!(v.cast::<i32>(), Vector3::new(1, -2, i32::MAX));
+        assert_eq!(
+            Vector2::new(300_i32, -1).cast::<u8>(),
+            Vector2::new(44, 255)
+        );
+        assert_eq!(
+            Vector2::new(3_i32, -4).cast::<f64>(),
+            Vector2::new(3.0, -4.0)
+        );
+    }
+
+    #[test]
+    fn try_cast() {
+        let v = Vector3::new(1.5_f64, -2.7, 100.0);
+        assert_eq!(v.try_cast::<i8>(), Some(Vector3::new(1, -2, 100)));
+        assert_eq!(v.try_cast::<u8>(), None);
+        assert_eq!(Vector2::new(f64::NAN, 0.0).try_cast::<i32>(), None);
+        assert_eq!(Vector2::new(1.0e300, 0.0).try_cast::<f32>(), None);
+        assert_eq!(
+            Vector2::new(255_i32, 0).try_cast::<u8>(),
+            Some(Vector2::new(255, 0))
+        );
+        assert_eq!(Vector2::new(256_i32, 0).try_cast::<u8>(), None);
+    }
+
+    #[test]
+    fn saturating_cast() {
+        let v = Vector3::new(300_i32, -5, 7);
+        assert_eq!(v.saturating_cast::<u8>(), Vector3::new(255, 0, 7));
+        assert_eq!(v.saturating_cast::<i8>(), Vector3::new(127, -5, 7));
+
+        let v = Vector3::new(f64::NAN, -1.0e300, 1.0e300);
+        assert_eq!(
+            v.saturating_cast::<i16>(),
+            Vector3::new(0, i16::MIN, i16::MAX)
+        );
+        assert_eq!(
+            v.saturating_cast::<f32>().yz(),
+            Vector2::new(f32::MIN, f32::MAX)
+        );
+        assert!(v.saturating_cast::<f32>().x().is_nan());
+    }
+
+    #[test]
+    fn from_widening() {
+        let v: Vector3<f64> = Vector3::new(1.5_f32, -2.0, 0.25).into();
+        assert_eq!(v, Vector3::new(1.5, -2.0, 0.25));
+        let v: Vector2<f32> = Vector2::new(200_u8, 7).into();
+        assert_eq!(v, Vector2::new(200.0, 7.0));
+        let v = Vector2::<i64>::from(Vector2::new(-3_i32, 4));
+        assert_eq!(v, Vector2::new(-3, 4));
+    }
+}
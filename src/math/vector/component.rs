@@ -0,0 +1,210 @@
+impl<T: VectorElement, const DIMENSION: usize> Vector<T, DIMENSION> {
+    pub fn sum(&self) -> T {
+        self.fold(T::zero(), |acc, e| acc + e)
+    }
+
+    pub fn product(&self) -> T {
+        self.fold(T::one(), |acc, e| acc * e)
+    }
+}
+
+impl<T, const DIMENSION: usize> Vector<T, DIMENSION>
+where
+    T: VectorElement + PartialOrd,
+{
+    pub fn min(&self, other: impl VectorLike<T, DIMENSION>) -> Self {
+        self.zip_map(other, |a, b| if b < a { b } else { a })
+    }
+
+    pub fn max(&self, other: impl VectorLike<T, DIMENSION>) -> Self {
+        self.zip_map(other, |a, b| if b > a { b } else { a })
+    }
+
+    pub fn clamp(
+        &self,
+        min: impl VectorLike<T, DIMENSION>,
+        max: impl VectorLike<T, DIMENSION>,
+    ) -> Self {
+        self.max(min).min(max)
+    }
+
+    pub fn min_element(&self) -> T {
+        self.elements[self.argmin()]
+    }
+
+    pub fn max_element(&self) -> T {
+        self.elements[self.argmax()]
+    }
+
+    // The index of the first smallest element.
+    pub fn argmin(&self) -> usize {
+        let mut index = 0;
+        for i in 1..DIMENSION {
+            if self.elements[i] < self.elements[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    // The index of the first largest element.
+    pub fn argmax(&self) -> usize {
+        let mut index = 0;
+        for i in 1..DIMENSION {
+            if self.elements[i] > self.elements[index] {
+                index = i;
+            }
+        }
+        index
+    }
+}
+
+impl<T, const DIMENSION: usize> Vector<T, DIMENSION>
+where
+    T: VectorElement + Signed,
+{
+    pub fn abs(&self) -> Self {
+        self.map(|e| e.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        self.map(|e| e.signum())
+    }
+}
+
+// Follows GLSL, so `fract` is `x - floor(x)` and stays non-negative, and
+// `step` and `smoothstep` take the edges as arguments and `self` as `x`.
+impl<T, const DIMENSION: usize> Vector<T, DIMENSION>
+where
+    T: VectorElement + Float,
+{
+    pub fn floor(&self) -> Self {
+        self.map(|e| e.floor())
+    }
+
+    pub fn ceil(&self) -> Self {
+        self.map(|e| e.ceil())
+    }
+
+    pub fn round(&self) -> Self {
+        self.map(|e| e.round())
+    }
+
+    pub fn fract(&self) -> Self {
+        self.map(|e| e - e.floor())
+    }
+
+    pub fn recip(&self) -> Self {
+        self.map(|e| e.recip())
+    }
+
+    pub fn powf(&self, n: T) -> Self {
+        self.map(|e| e.powf(n))
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.map(|e| e.sqrt())
+    }
+
+    pub fn lerp(&self, other: impl VectorLike<T, DIMENSION>, t: T) -> Self {
+        self.zip_map(other, |a, b| a + (b - a) * t)
+    }
+
+    pub fn step(&self, edge: impl VectorLike<T, DIMENSION>) -> Self {
+        self.zip_map(edge, |x, edge| if x < edge { T::zero() } else { T::one() })
+    }
+
+    pub fn smoothstep(
+        &self,
+        edge0: impl VectorLike<T, DIMENSION>,
+        edge1: impl VectorLike<T, DIMENSION>,
+    ) -> Self {
+        let edge0 = edge0.to_vector();
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let mut result = *self;
+        for i in 0..DIMENSION {
+            let t = (self.elements[i] - edge0[i]) / (*edge1.get(i) - edge0[i]);
+            let t = t.max(T::zero()).min(T::one());
+            result.elements[i] = t * t * (three - two * t);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod component_test {
+    use super::*;
+    type Vector3<T> = Vector<T, 3>;
+    type Vector4<T> = Vector<T, 4>;
+
+    #[test]
+    fn sum_product() {
+        let v = Vector4::new(1, 2, 3, 4);
+        assert_eq!(v.sum(), 10);
+        assert_eq!(v.product(), 24);
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Vector3::new(1, 5, -3);
+        let b = Vector3::new(2, 4, -3);
+        assert_eq!(a.min(b), Vector3::new(1, 4, -3));
+        assert_eq!(a.max(b), Vector3::new(2, 5, -3));
+        assert_eq!(a.clamp([0, 0, 0], [3, 3, 3]), Vector3::new(1, 3, 0));
+        assert_eq!(a.min_element(), -3);
+        assert_eq!(a.max_element(), 5);
+        assert_eq!(a.argmin(), 2);
+        assert_eq!(a.argmax(), 1);
+        assert_eq!(Vector3::new(2, 1, 1).argmin(), 1);
+    }
+
+    #[test]
+    fn abs_signum() {
+        let v = Vector3::new(-2, 0, 3);
+        assert_eq!(v.abs(), Vector3::new(2, 0, 3));
+        assert_eq!(v.signum(), Vector3::new(-1, 0, 1));
+        assert_eq!(
+            Vector3::new(-2.5, 0.5, 3.0).abs(),
+            Vector3::new(2.5, 0.5, 3.0)
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Vector4::new(-1.5, -0.25, 0.5, 2.75);
+        assert_eq!(v.floor(), Vector4::new(-2.0, -1.0, 0.0, 2.0));
+        assert_eq!(v.ceil(), Vector4::new(-1.0, -0.0, 1.0, 3.0));
+        assert_eq!(v.round(), Vector4::new(-2.0, -0.0, 1.0, 3.0));
+        assert_eq!(v.fract(), Vector4::new(0.5, 0.75, 0.5, 0.75));
+    }
+
+    #[test]
+    fn powers() {
+        let v = Vector3::new(1.0, 4.0, 0.25);
+        assert_eq!(v.recip(), Vector3::new(1.0, 0.25, 4.0));
+        assert_eq!(v.sqrt(), Vector3::new(1.0, 2.0, 0.5));
+        assert_eq!(v.powf(2.0), Vector3::new(1.0, 16.0, 0.0625));
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vector3::new(0.0, 2.0, -4.0);
+        let b = Vector3::new(4.0, 2.0, 4.0);
+        assert_eq!(a.lerp(b, 0.25), Vector3::new(1.0, 2.0, -2.0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn step() {
+        let v = Vector3::new(0.2, 0.5, 0.8);
+        assert_eq!(v.step([0.5; 3]), Vector3::new(0.0, 1.0, 1.0));
+        let s = v.smoothstep([0.0; 3], [1.0; 3]);
+        assert!((s - Vector3::new(0.104, 0.5, 0.896)).length() < 1.0e-12);
+        assert_eq!(
+            Vector3::new(-1.0, 0.5, 2.0).smoothstep([0.0; 3], [1.0; 3]),
+            Vector3::new(0.0, 0.5, 1.0)
+        );
+    }
+}
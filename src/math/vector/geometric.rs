@@ -0,0 +1,244 @@
+impl<T, const DIMENSION: usize> Vector<T, DIMENSION>
+where
+    T: VectorElement + Float,
+{
+    // Projecting onto a zero vector gives zero, like `normalized`.
+    pub fn project_onto(&self, other: impl VectorLike<T, DIMENSION>) -> Self {
+        let other = other.to_vector();
+        let length_squared = other.length_squared();
+        if length_squared.is_zero() {
+            return Self::zero();
+        }
+        other * (self.dot(other) / length_squared)
+    }
+
+    pub fn reject_from(&self, other: impl VectorLike<T, DIMENSION>) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    // Kahan's formula, which stays accurate for nearly parallel and nearly
+    // opposite vectors where `acos` of the dot product does not.
+    pub fn angle_between(&self, other: impl VectorLike<T, DIMENSION>) -> Rad<T> {
+        let other = other.to_vector();
+        let a = *self * other.length();
+        let b = other * self.length();
+        let two = T::one() + T::one();
+        Rad(two * (a - b).length().atan2((a + b).length()))
+    }
+
+    pub fn any_orthogonal(&self) -> Self {
+        const {
+            assert!(DIMENSION >= 2);
+        }
+        let j = self.map(|e| e.abs()).argmax();
+        let k = if j == 0 { 1 } else { 0 };
+        let mut result = Self::zero();
+        result.elements[k] = self.elements[j];
+        result.elements[j] = -self.elements[k];
+        result.normalized()
+    }
+
+    // Meant for unit vectors, for which the result stays a unit vector.
+    // Opposite vectors are interpolated through `any_orthogonal`.
+    pub fn slerp(&self, other: impl VectorLike<T, DIMENSION>, t: T) -> Self {
+        let other = other.to_vector();
+        let theta = self.angle_between(other).0;
+        let s = theta.sin();
+        if s <= T::epsilon() {
+            if self.dot(other) >= T::zero() {
+                return self.lerp(other, t);
+            }
+            let (s, c) = (theta * t).sin_cos();
+            return *self * c + self.any_orthogonal() * (self.length() * s);
+        }
+        *self * (((T::one() - t) * theta).sin() / s) + other * ((t * theta).sin() / s)
+    }
+
+    // Rotates towards the direction of `target` by at most `max_angle`,
+    // reaching `target` if it is close enough.
+    pub fn rotate_towards(
+        &self,
+        target: impl VectorLike<T, DIMENSION>,
+        max_angle: impl Into<Rad<T>>,
+    ) -> Self {
+        let target = target.to_vector();
+        let theta = self.angle_between(target).0;
+        let max_angle = max_angle.into().0;
+        if theta <= max_angle {
+            target
+        } else {
+            self.slerp(target, max_angle / theta)
+        }
+    }
+
+    // Modified Gram-Schmidt. Vectors that depend linearly on the ones before
+    // them are returned as zero.
+    pub fn gram_schmidt<const COUNT: usize>(vectors: [Self; COUNT]) -> [Self; COUNT] {
+        let tolerance = T::epsilon().sqrt();
+        let mut result = vectors;
+        for i in 0..COUNT {
+            let length = result[i].length();
+            for j in 0..i {
+                let projection = result[j] * result[i].dot(result[j]);
+                result[i] -= projection;
+            }
+            result[i] = if result[i].length() <= tolerance * length {
+                Self::zero()
+            } else {
+                result[i].normalized()
+            };
+        }
+        result
+    }
+}
+
+impl<T: VectorElement + Float> Vector<T, 3> {
+    // Returns two vectors that form a right-handed orthonormal basis with
+    // `self`, which must be normalized. Duff et al., "Building an Orthonormal
+    // Basis, Revisited", 2017.
+    pub fn orthonormal_basis(&self) -> (Self, Self) {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        let sign = if z.is_sign_negative() {
+            -T::one()
+        } else {
+            T::one()
+        };
+        let a = -T::one() / (sign + z);
+        let b = x * y * a;
+        (
+            Self::new(T::one() + sign * x * x * a, sign * b, -sign * x),
+            Self::new(b, sign + y * y * a, -y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod geometric_test {
+    use super::*;
+    use crate::math::angle::Deg;
+    use crate::math::approx::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    type Vector2<T> = Vector<T, 2>;
+    type Vector3<T> = Vector<T, 3>;
+
+    #[test]
+    fn project_reject() {
+        let v = Vector3::new(3.0, 4.0, 5.0);
+        assert_eq!(v.project_onto([0.0, 2.0, 0.0]), Vector3::new(0.0, 4.0, 0.0));
+        assert_eq!(v.reject_from([0.0, 2.0, 0.0]), Vector3::new(3.0, 0.0, 5.0));
+
+        let v = Vector2::new(1.0, 1.0);
+        assert_eq!(v.project_onto([2.0, 0.0]), Vector2::new(1.0, 0.0));
+        assert_eq!(v.reject_from([2.0, 0.0]), Vector2::new(0.0, 1.0));
+        assert_eq!(v.project_onto(Vector2::zero()), Vector2::zero());
+        assert_eq!(v.reject_from(Vector2::zero()), v);
+
+        let v = Vector2::new(2.0, 1.0);
+        let onto = Vector2::new(1.0, 1.0);
+        assert_approx_eq(v.project_onto(onto), Vector2::new(1.5, 1.5), 1.0e-10);
+        assert!(v.reject_from(onto).dot(onto).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn angle_between() {
+        let x = Vector3::<f64>::unit_x();
+        assert!((x.angle_between(Vector3::unit_y()).0 - FRAC_PI_2).abs() < 1.0e-12);
+        assert!((x.angle_between([2.0, 2.0, 0.0]).0 - FRAC_PI_4).abs() < 1.0e-12);
+        assert!((x.angle_between(-x).0 - PI).abs() < 1.0e-12);
+        assert_eq!(x.angle_between(x * 3.0).0, 0.0);
+
+        let tiny = Vector3::new(1.0, 1.0e-9, 0.0);
+        assert!((x.angle_between(tiny).0 - 1.0e-9).abs() < 1.0e-18);
+    }
+
+    #[test]
+    fn any_orthogonal() {
+        for v in [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::unit_x(),
+            Vector3::new(0.0, 0.0, -2.0),
+        ] {
+            let o = v.any_orthogonal();
+            assert!(o.dot(v).abs() < 1.0e-12);
+            assert!((o.length() - 1.0).abs() < 1.0e-12);
+        }
+        let o = Vector2::new(3.0, 4.0).any_orthogonal();
+        assert_approx_eq(o, Vector2::new(0.8, -0.6), 1.0e-10);
+    }
+
+    #[test]
+    fn slerp() {
+        let x = Vector3::<f64>::unit_x();
+        let y = Vector3::unit_y();
+        let half = 0.5_f64.sqrt();
+        assert_approx_eq(x.slerp(y, 0.5), Vector3::new(half, half, 0.0), 1.0e-10);
+        assert_approx_eq(x.slerp(y, 0.0), x, 1.0e-10);
+        assert_approx_eq(x.slerp(y, 1.0), y, 1.0e-10);
+        assert_approx_eq(x.slerp(x, 0.3), x, 1.0e-10);
+
+        let v = x.slerp(-x, 0.5);
+        assert!(v.dot(x).abs() < 1.0e-12);
+        assert!((v.length() - 1.0).abs() < 1.0e-12);
+        assert_approx_eq(x.slerp(-x, 1.0), -x, 1.0e-10);
+    }
+
+    #[test]
+    fn rotate_towards() {
+        let x = Vector3::<f64>::unit_x();
+        let y = Vector3::unit_y();
+        let half = 0.5_f64.sqrt();
+        assert_approx_eq(
+            x.rotate_towards(y, FRAC_PI_4),
+            Vector3::new(half, half, 0.0),
+            1.0e-10,
+        );
+        assert_approx_eq(x.rotate_towards(y, Deg(90.0)), y, 1.0e-10);
+        assert_approx_eq(x.rotate_towards(y, PI), y, 1.0e-10);
+        assert_approx_eq(x.rotate_towards(y, 0.0), x, 1.0e-10);
+    }
+
+    #[test]
+    fn orthonormal_basis() {
+        for n in [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-1.0, 0.5, -3.0),
+            Vector3::unit_z(),
+            -Vector3::unit_z(),
+            Vector3::unit_x(),
+        ] {
+            let n = n.normalized();
+            let (b1, b2) = n.orthonormal_basis();
+            assert!((b1.length() - 1.0).abs() < 1.0e-12);
+            assert!((b2.length() - 1.0).abs() < 1.0e-12);
+            assert!(b1.dot(n).abs() < 1.0e-12);
+            assert!(b2.dot(n).abs() < 1.0e-12);
+            assert!(b1.dot(b2).abs() < 1.0e-12);
+            assert_approx_eq(b1.cross(b2), n, 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn gram_schmidt() {
+        let basis = Vector3::gram_schmidt([
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(0.0, 1.0, 1.0),
+        ]);
+        for i in 0..3 {
+            assert!((basis[i].length() - 1.0).abs() < 1.0e-12);
+            for j in 0..i {
+                assert!(basis[i].dot(basis[j]).abs() < 1.0e-12);
+            }
+        }
+        let half = 0.5_f64.sqrt();
+        assert_approx_eq(basis[0], Vector3::new(half, half, 0.0), 1.0e-10);
+
+        let basis = Vector3::gram_schmidt([
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+        ]);
+        assert_eq!(basis[1], Vector3::zero());
+        assert_approx_eq(basis[2], Vector3::unit_y(), 1.0e-10);
+    }
+}
@@ -0,0 +1,148 @@
+impl<T: VectorElement, const DIMENSION: usize> Vector<T, DIMENSION> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.elements.iter_mut()
+    }
+
+    // Returns `None` unless the iterator yields exactly `DIMENSION` elements.
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let mut elements = [T::default(); DIMENSION];
+        for element in elements.iter_mut() {
+            *element = iter.next()?;
+        }
+        match iter.next() {
+            Some(_) => None,
+            None => Some(Self { elements }),
+        }
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> IntoIterator for Vector<T, DIMENSION> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, DIMENSION>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, T: VectorElement, const DIMENSION: usize> IntoIterator for &'a Vector<T, DIMENSION> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
+
+impl<'a, T: VectorElement, const DIMENSION: usize> IntoIterator for &'a mut Vector<T, DIMENSION> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter_mut()
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> AsRef<[T]> for Vector<T, DIMENSION> {
+    fn as_ref(&self) -> &[T] {
+        &self.elements
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> AsMut<[T]> for Vector<T, DIMENSION> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> Deref for Vector<T, DIMENSION> {
+    type Target = [T; DIMENSION];
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<T: VectorElement, const DIMENSION: usize> std::iter::Sum for Vector<T, DIMENSION> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T: VectorElement, const DIMENSION: usize> std::iter::Sum<&'a Self>
+    for Vector<T, DIMENSION>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + *v)
+    }
+}
+
+#[cfg(test)]
+mod iter_test {
+    use super::*;
+    type Vector3<T> = Vector<T, 3>;
+
+    #[test]
+    fn into_iter() {
+        let mut v = Vector3::new(1, 2, 3);
+        assert_eq!(v.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((&v).into_iter().copied().max(), Some(3));
+        for e in &mut v {
+            *e *= 10;
+        }
+        assert_eq!(v, Vector3::new(10, 20, 30));
+        let mut total = 0;
+        for e in v {
+            total += e;
+        }
+        assert_eq!(total, 60);
+    }
+
+    #[test]
+    fn iter() {
+        let mut v = Vector3::new(1, 2, 3);
+        assert_eq!(v.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+        v.iter_mut().for_each(|e| *e = -*e);
+        assert_eq!(v, Vector3::new(-1, -2, -3));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        assert_eq!(Vector3::try_from_iter(1..4), Some(Vector3::new(1, 2, 3)));
+        assert_eq!(Vector3::try_from_iter(1..3), None);
+        assert_eq!(Vector3::try_from_iter(1..5), None);
+        let v = Vector3::try_from_iter([1.0, 2.0, 3.0].iter().map(|e| e * 2.0));
+        assert_eq!(v, Some(Vector3::new(2.0, 4.0, 6.0)));
+    }
+
+    #[test]
+    fn slices() {
+        let mut v = Vector3::new(1, 2, 3);
+        assert_eq!(v.as_ref(), &[1, 2, 3]);
+        v.as_mut()[1] = 5;
+        assert_eq!(*v, [1, 5, 3]);
+        assert_eq!(v.len(), 3);
+        assert!(v.contains(&5));
+        assert_eq!(v.as_slice().iter().sum::<i32>(), 9);
+    }
+
+    #[test]
+    fn sum() {
+        let vs = [
+            Vector3::new(1, 2, 3),
+            Vector3::new(4, 5, 6),
+            Vector3::new(-1, 0, 1),
+        ];
+        assert_eq!(vs.iter().sum::<Vector3<i32>>(), Vector3::new(4, 7, 10));
+        assert_eq!(vs.into_iter().sum::<Vector3<i32>>(), Vector3::new(4, 7, 10));
+        assert_eq!(
+            std::iter::empty::<Vector3<f64>>().sum::<Vector3<f64>>(),
+            Vector3::zero()
+        );
+    }
+}
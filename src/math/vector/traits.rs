@@ -5,7 +5,10 @@ pub trait VectorElement:
 {
 }
 
-impl<T> VectorElement for T where T: Num + NumAssignOps<Self> + Clone + Copy + std::fmt::Debug + Default {}
+impl<T> VectorElement for T where
+    T: Num + NumAssignOps<Self> + Clone + Copy + std::fmt::Debug + Default
+{
+}
 
 pub trait VectorLike<T: VectorElement, const DIMENSION: usize> {
     fn get(&self, index: usize) -> &T;
@@ -395,6 +395,932 @@ impl_xyzw_accessor!(min_vector_dimension 4; 4, zzxw, 2, 2, 0, 3);
 impl_xyzw_accessor!(min_vector_dimension 4; 4, zzyw, 2, 2, 1, 3);
 impl_xyzw_accessor!(min_vector_dimension 4; 4, zzzw, 2, 2, 2, 3);
 
+impl_xyzw_accessor!(min_vector_dimension 1; 1, r, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 2, rr, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 3, rrr, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 4, rrrr, 0, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 1, g, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, rg, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, gr, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, gg, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, rrg, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, rgr, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, rgg, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, grr, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, grg, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, ggr, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, ggg, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rrrg, 0, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rrgr, 0, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rrgg, 0, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rgrr, 0, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rgrg, 0, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rggr, 0, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, rggg, 0, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, grrr, 1, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, grrg, 1, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, grgr, 1, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, grgg, 1, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ggrr, 1, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ggrg, 1, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, gggr, 1, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, gggg, 1, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 1, b, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, rb, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, gb, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, br, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, bg, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, bb, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, rrb, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, rgb, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, rbr, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, rbg, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, rbb, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, grb, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ggb, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, gbr, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, gbg, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, gbb, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, brr, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, brg, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, brb, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bgr, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bgg, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bgb, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bbr, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bbg, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, bbb, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rrrb, 0, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rrgb, 0, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rrbr, 0, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rrbg, 0, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rrbb, 0, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rgrb, 0, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rggb, 0, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rgbr, 0, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rgbg, 0, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rgbb, 0, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbrr, 0, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbrg, 0, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbrb, 0, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbgr, 0, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbgg, 0, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbgb, 0, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbbr, 0, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbbg, 0, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, rbbb, 0, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, grrb, 1, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, grgb, 1, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, grbr, 1, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, grbg, 1, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, grbb, 1, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ggrb, 1, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gggb, 1, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ggbr, 1, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ggbg, 1, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ggbb, 1, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbrr, 1, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbrg, 1, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbrb, 1, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbgr, 1, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbgg, 1, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbgb, 1, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbbr, 1, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbbg, 1, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, gbbb, 1, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brrr, 2, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brrg, 2, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brrb, 2, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brgr, 2, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brgg, 2, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brgb, 2, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brbr, 2, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brbg, 2, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, brbb, 2, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgrr, 2, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgrg, 2, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgrb, 2, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bggr, 2, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bggg, 2, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bggb, 2, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgbr, 2, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgbg, 2, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bgbb, 2, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbrr, 2, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbrg, 2, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbrb, 2, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbgr, 2, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbgg, 2, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbgb, 2, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbbr, 2, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbbg, 2, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, bbbb, 2, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 1, a, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, aa, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ar, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ag, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ab, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ra, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ga, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, ba, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aaa, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aar, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aag, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aab, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, ara, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, arr, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, arg, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, arb, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aga, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, agr, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, agg, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, agb, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, aba, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, abr, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, abg, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, abb, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, raa, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rar, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rag, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rab, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rra, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rga, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, rba, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gaa, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gar, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gag, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gab, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gra, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gga, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, gba, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, baa, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bar, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bag, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bab, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bra, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bga, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, bba, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaaa, 3, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaar, 3, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaag, 3, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaab, 3, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aara, 3, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aarr, 3, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aarg, 3, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aarb, 3, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaga, 3, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aagr, 3, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aagg, 3, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aagb, 3, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aaba, 3, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aabr, 3, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aabg, 3, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aabb, 3, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, araa, 3, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arar, 3, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arag, 3, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arab, 3, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arra, 3, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arrr, 3, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arrg, 3, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arrb, 3, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arga, 3, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, argr, 3, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, argg, 3, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, argb, 3, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arba, 3, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arbr, 3, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arbg, 3, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, arbb, 3, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agaa, 3, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agar, 3, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agag, 3, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agab, 3, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agra, 3, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agrr, 3, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agrg, 3, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agrb, 3, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agga, 3, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aggr, 3, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aggg, 3, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, aggb, 3, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agba, 3, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agbr, 3, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agbg, 3, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, agbb, 3, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abaa, 3, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abar, 3, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abag, 3, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abab, 3, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abra, 3, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abrr, 3, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abrg, 3, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abrb, 3, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abga, 3, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abgr, 3, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abgg, 3, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abgb, 3, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abba, 3, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abbr, 3, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abbg, 3, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, abbb, 3, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raaa, 0, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raar, 0, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raag, 0, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raab, 0, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rara, 0, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rarr, 0, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rarg, 0, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rarb, 0, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raga, 0, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ragr, 0, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ragg, 0, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ragb, 0, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, raba, 0, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rabr, 0, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rabg, 0, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rabb, 0, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rraa, 0, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrar, 0, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrag, 0, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrab, 0, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrra, 0, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrga, 0, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rrba, 0, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgaa, 0, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgar, 0, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgag, 0, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgab, 0, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgra, 0, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgga, 0, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rgba, 0, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbaa, 0, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbar, 0, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbag, 0, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbab, 0, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbra, 0, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbga, 0, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, rbba, 0, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaaa, 1, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaar, 1, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaag, 1, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaab, 1, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gara, 1, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, garr, 1, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, garg, 1, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, garb, 1, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaga, 1, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gagr, 1, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gagg, 1, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gagb, 1, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gaba, 1, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gabr, 1, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gabg, 1, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gabb, 1, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, graa, 1, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grar, 1, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grag, 1, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grab, 1, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grra, 1, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grga, 1, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, grba, 1, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggaa, 1, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggar, 1, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggag, 1, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggab, 1, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggra, 1, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggga, 1, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ggba, 1, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbaa, 1, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbar, 1, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbag, 1, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbab, 1, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbra, 1, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbga, 1, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, gbba, 1, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baaa, 2, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baar, 2, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baag, 2, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baab, 2, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bara, 2, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, barr, 2, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, barg, 2, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, barb, 2, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baga, 2, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bagr, 2, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bagg, 2, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bagb, 2, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, baba, 2, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, babr, 2, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, babg, 2, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, babb, 2, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, braa, 2, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brar, 2, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brag, 2, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brab, 2, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brra, 2, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brga, 2, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, brba, 2, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgaa, 2, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgar, 2, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgag, 2, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgab, 2, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgra, 2, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgga, 2, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bgba, 2, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbaa, 2, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbar, 2, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbag, 2, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbab, 2, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbra, 2, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbga, 2, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, bbba, 2, 2, 2, 3);
+
+impl_xyzw_accessor!(min_vector_dimension 1; 1, s, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 2, ss, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 3, sss, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 1; 4, ssss, 0, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 1, t, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, st, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, ts, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 2, tt, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, sst, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, sts, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, stt, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, tss, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, tst, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, tts, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 3, ttt, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ssst, 0, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ssts, 0, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, sstt, 0, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, stss, 0, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, stst, 0, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, stts, 0, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, sttt, 0, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, tsss, 1, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, tsst, 1, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, tsts, 1, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, tstt, 1, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ttss, 1, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ttst, 1, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, ttts, 1, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 2; 4, tttt, 1, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 1, p, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, sp, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, tp, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, ps, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, pt, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 2, pp, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ssp, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, stp, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, sps, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, spt, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, spp, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, tsp, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ttp, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, tps, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, tpt, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, tpp, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, pss, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, pst, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, psp, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, pts, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ptt, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ptp, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, pps, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ppt, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 3, ppp, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sssp, 0, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sstp, 0, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ssps, 0, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sspt, 0, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sspp, 0, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, stsp, 0, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sttp, 0, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, stps, 0, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, stpt, 0, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, stpp, 0, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, spss, 0, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, spst, 0, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, spsp, 0, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, spts, 0, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sptt, 0, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sptp, 0, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, spps, 0, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sppt, 0, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, sppp, 0, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tssp, 1, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tstp, 1, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tsps, 1, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tspt, 1, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tspp, 1, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ttsp, 1, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tttp, 1, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ttps, 1, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ttpt, 1, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ttpp, 1, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tpss, 1, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tpst, 1, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tpsp, 1, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tpts, 1, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tptt, 1, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tptp, 1, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tpps, 1, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tppt, 1, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, tppp, 1, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, psss, 2, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, psst, 2, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pssp, 2, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, psts, 2, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pstt, 2, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pstp, 2, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, psps, 2, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pspt, 2, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pspp, 2, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptss, 2, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptst, 2, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptsp, 2, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptts, 2, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pttt, 2, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pttp, 2, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptps, 2, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptpt, 2, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ptpp, 2, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ppss, 2, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ppst, 2, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ppsp, 2, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ppts, 2, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pptt, 2, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pptp, 2, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, ppps, 2, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pppt, 2, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 3; 4, pppp, 2, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 1, q, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, qq, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, qs, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, qt, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, qp, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, sq, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, tq, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 2, pq, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qqq, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qqs, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qqt, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qqp, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qsq, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qss, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qst, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qsp, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qtq, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qts, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qtt, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qtp, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qpq, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qps, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qpt, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, qpp, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, sqq, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, sqs, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, sqt, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, sqp, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, ssq, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, stq, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, spq, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tqq, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tqs, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tqt, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tqp, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tsq, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, ttq, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, tpq, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, pqq, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, pqs, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, pqt, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, pqp, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, psq, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, ptq, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 3, ppq, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqqq, 3, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqqs, 3, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqqt, 3, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqqp, 3, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqsq, 3, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqss, 3, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqst, 3, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqsp, 3, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqtq, 3, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqts, 3, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqtt, 3, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqtp, 3, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqpq, 3, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqps, 3, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqpt, 3, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qqpp, 3, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsqq, 3, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsqs, 3, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsqt, 3, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsqp, 3, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qssq, 3, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsss, 3, 0, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsst, 3, 0, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qssp, 3, 0, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qstq, 3, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsts, 3, 0, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qstt, 3, 0, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qstp, 3, 0, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qspq, 3, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qsps, 3, 0, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qspt, 3, 0, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qspp, 3, 0, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtqq, 3, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtqs, 3, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtqt, 3, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtqp, 3, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtsq, 3, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtss, 3, 1, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtst, 3, 1, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtsp, 3, 1, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qttq, 3, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtts, 3, 1, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qttt, 3, 1, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qttp, 3, 1, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtpq, 3, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtps, 3, 1, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtpt, 3, 1, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qtpp, 3, 1, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpqq, 3, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpqs, 3, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpqt, 3, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpqp, 3, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpsq, 3, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpss, 3, 2, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpst, 3, 2, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpsp, 3, 2, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qptq, 3, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpts, 3, 2, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qptt, 3, 2, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qptp, 3, 2, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qppq, 3, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qpps, 3, 2, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qppt, 3, 2, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, qppp, 3, 2, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqqq, 0, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqqs, 0, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqqt, 0, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqqp, 0, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqsq, 0, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqss, 0, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqst, 0, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqsp, 0, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqtq, 0, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqts, 0, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqtt, 0, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqtp, 0, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqpq, 0, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqps, 0, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqpt, 0, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sqpp, 0, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ssqq, 0, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ssqs, 0, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ssqt, 0, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ssqp, 0, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sssq, 0, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sstq, 0, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sspq, 0, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stqq, 0, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stqs, 0, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stqt, 0, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stqp, 0, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stsq, 0, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sttq, 0, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, stpq, 0, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, spqq, 0, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, spqs, 0, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, spqt, 0, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, spqp, 0, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, spsq, 0, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sptq, 0, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, sppq, 0, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqqq, 1, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqqs, 1, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqqt, 1, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqqp, 1, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqsq, 1, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqss, 1, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqst, 1, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqsp, 1, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqtq, 1, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqts, 1, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqtt, 1, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqtp, 1, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqpq, 1, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqps, 1, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqpt, 1, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tqpp, 1, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tsqq, 1, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tsqs, 1, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tsqt, 1, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tsqp, 1, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tssq, 1, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tstq, 1, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tspq, 1, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttqq, 1, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttqs, 1, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttqt, 1, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttqp, 1, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttsq, 1, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tttq, 1, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ttpq, 1, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tpqq, 1, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tpqs, 1, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tpqt, 1, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tpqp, 1, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tpsq, 1, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tptq, 1, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, tppq, 1, 2, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqqq, 2, 3, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqqs, 2, 3, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqqt, 2, 3, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqqp, 2, 3, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqsq, 2, 3, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqss, 2, 3, 0, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqst, 2, 3, 0, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqsp, 2, 3, 0, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqtq, 2, 3, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqts, 2, 3, 1, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqtt, 2, 3, 1, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqtp, 2, 3, 1, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqpq, 2, 3, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqps, 2, 3, 2, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqpt, 2, 3, 2, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pqpp, 2, 3, 2, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, psqq, 2, 0, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, psqs, 2, 0, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, psqt, 2, 0, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, psqp, 2, 0, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pssq, 2, 0, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pstq, 2, 0, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pspq, 2, 0, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptqq, 2, 1, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptqs, 2, 1, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptqt, 2, 1, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptqp, 2, 1, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptsq, 2, 1, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pttq, 2, 1, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ptpq, 2, 1, 2, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ppqq, 2, 2, 3, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ppqs, 2, 2, 3, 0);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ppqt, 2, 2, 3, 1);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ppqp, 2, 2, 3, 2);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, ppsq, 2, 2, 0, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pptq, 2, 2, 1, 3);
+impl_xyzw_accessor!(min_vector_dimension 4; 4, pppq, 2, 2, 2, 3);
+
+macro_rules! impl_xyzw_setter {
+    (min_vector_dimension 1; $($rest: tt)+) => {
+        impl_xyzw_setter!(dimension 1; $($rest)+);
+        impl_xyzw_setter!(min_vector_dimension 2; $($rest)+);
+    };
+    (min_vector_dimension 2; $($rest: tt)+) => {
+        impl_xyzw_setter!(dimension 2; $($rest)+);
+        impl_xyzw_setter!(min_vector_dimension 3; $($rest)+);
+    };
+    (min_vector_dimension 3; $($rest: tt)+) => {
+        impl_xyzw_setter!(dimension 3; $($rest)+);
+        impl_xyzw_setter!(min_vector_dimension 4; $($rest)+);
+    };
+    (min_vector_dimension 4; $($rest: tt)+) => {
+        impl_xyzw_setter!(dimension 4; $($rest)+);
+    };
+
+    (dimension $vector_dimension: expr; 1, $set: ident, $with: ident, $get_mut: ident, $index: expr) => {
+        impl<T: VectorElement> Vector<T, $vector_dimension> {
+            pub fn $set(&mut self, value: T) {
+                self.elements[$index] = value;
+            }
+
+            pub fn $with(mut self, value: T) -> Self {
+                self.elements[$index] = value;
+                self
+            }
+
+            pub fn $get_mut(&mut self) -> &mut T {
+                &mut self.elements[$index]
+            }
+        }
+    };
+    (dimension $vector_dimension: expr; $value_dimension: expr, $set: ident, $with: ident, $($index: expr),+) => {
+        impl<T: VectorElement> Vector<T, $vector_dimension> {
+            pub fn $set(&mut self, value: impl VectorLike<T, $value_dimension>) {
+                for (i, index) in [$($index),+].into_iter().enumerate() {
+                    self.elements[index] = *value.get(i);
+                }
+            }
+
+            pub fn $with(mut self, value: impl VectorLike<T, $value_dimension>) -> Self {
+                self.$set(value);
+                self
+            }
+        }
+    };
+}
+
+impl_xyzw_setter!(min_vector_dimension 1; 1, set_x, with_x, x_mut, 0);
+impl_xyzw_setter!(min_vector_dimension 2; 1, set_y, with_y, y_mut, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 1, set_z, with_z, z_mut, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 1, set_w, with_w, w_mut, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_xy, with_xy, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_xz, with_xz, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_xw, with_xw, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_yx, with_yx, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_yz, with_yz, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_yw, with_yw, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_zx, with_zx, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_zy, with_zy, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_zw, with_zw, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_wx, with_wx, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_wy, with_wy, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_wz, with_wz, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_xyz, with_xyz, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_xyw, with_xyw, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_xzy, with_xzy, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_xzw, with_xzw, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_xwy, with_xwy, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_xwz, with_xwz, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_yxz, with_yxz, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_yxw, with_yxw, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_yzx, with_yzx, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_yzw, with_yzw, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_ywx, with_ywx, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_ywz, with_ywz, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_zxy, with_zxy, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_zxw, with_zxw, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_zyx, with_zyx, 2, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_zyw, with_zyw, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_zwx, with_zwx, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_zwy, with_zwy, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wxy, with_wxy, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wxz, with_wxz, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wyx, with_wyx, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wyz, with_wyz, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wzx, with_wzx, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_wzy, with_wzy, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xyzw, with_xyzw, 0, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xywz, with_xywz, 0, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xzyw, with_xzyw, 0, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xzwy, with_xzwy, 0, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xwyz, with_xwyz, 0, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_xwzy, with_xwzy, 0, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_yxzw, with_yxzw, 1, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_yxwz, with_yxwz, 1, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_yzxw, with_yzxw, 1, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_yzwx, with_yzwx, 1, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_ywxz, with_ywxz, 1, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_ywzx, with_ywzx, 1, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zxyw, with_zxyw, 2, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zxwy, with_zxwy, 2, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zyxw, with_zyxw, 2, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zywx, with_zywx, 2, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zwxy, with_zwxy, 2, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_zwyx, with_zwyx, 2, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wxyz, with_wxyz, 3, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wxzy, with_wxzy, 3, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wyxz, with_wyxz, 3, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wyzx, with_wyzx, 3, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wzxy, with_wzxy, 3, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_wzyx, with_wzyx, 3, 2, 1, 0);
+
+impl_xyzw_setter!(min_vector_dimension 1; 1, set_r, with_r, r_mut, 0);
+impl_xyzw_setter!(min_vector_dimension 2; 1, set_g, with_g, g_mut, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 1, set_b, with_b, b_mut, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 1, set_a, with_a, a_mut, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_rg, with_rg, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_rb, with_rb, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ra, with_ra, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_gr, with_gr, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_gb, with_gb, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ga, with_ga, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_br, with_br, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_bg, with_bg, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ba, with_ba, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ar, with_ar, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ag, with_ag, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_ab, with_ab, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_rgb, with_rgb, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_rga, with_rga, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_rbg, with_rbg, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_rba, with_rba, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_rag, with_rag, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_rab, with_rab, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_grb, with_grb, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_gra, with_gra, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_gbr, with_gbr, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_gba, with_gba, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_gar, with_gar, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_gab, with_gab, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_brg, with_brg, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_bra, with_bra, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_bgr, with_bgr, 2, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_bga, with_bga, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_bar, with_bar, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_bag, with_bag, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_arg, with_arg, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_arb, with_arb, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_agr, with_agr, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_agb, with_agb, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_abr, with_abr, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_abg, with_abg, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_rgba, with_rgba, 0, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_rgab, with_rgab, 0, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_rbga, with_rbga, 0, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_rbag, with_rbag, 0, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_ragb, with_ragb, 0, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_rabg, with_rabg, 0, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_grba, with_grba, 1, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_grab, with_grab, 1, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_gbra, with_gbra, 1, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_gbar, with_gbar, 1, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_garb, with_garb, 1, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_gabr, with_gabr, 1, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_brga, with_brga, 2, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_brag, with_brag, 2, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_bgra, with_bgra, 2, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_bgar, with_bgar, 2, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_barg, with_barg, 2, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_bagr, with_bagr, 2, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_argb, with_argb, 3, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_arbg, with_arbg, 3, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_agrb, with_agrb, 3, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_agbr, with_agbr, 3, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_abrg, with_abrg, 3, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_abgr, with_abgr, 3, 2, 1, 0);
+
+impl_xyzw_setter!(min_vector_dimension 1; 1, set_s, with_s, s_mut, 0);
+impl_xyzw_setter!(min_vector_dimension 2; 1, set_t, with_t, t_mut, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 1, set_p, with_p, p_mut, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 1, set_q, with_q, q_mut, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_st, with_st, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_sp, with_sp, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_sq, with_sq, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 2; 2, set_ts, with_ts, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_tp, with_tp, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_tq, with_tq, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_ps, with_ps, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 3; 2, set_pt, with_pt, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_pq, with_pq, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_qs, with_qs, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_qt, with_qt, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 2, set_qp, with_qp, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_stp, with_stp, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_stq, with_stq, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_spt, with_spt, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_spq, with_spq, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_sqt, with_sqt, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_sqp, with_sqp, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_tsp, with_tsp, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_tsq, with_tsq, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_tps, with_tps, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_tpq, with_tpq, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_tqs, with_tqs, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_tqp, with_tqp, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_pst, with_pst, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_psq, with_psq, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 3; 3, set_pts, with_pts, 2, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_ptq, with_ptq, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_pqs, with_pqs, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_pqt, with_pqt, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qst, with_qst, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qsp, with_qsp, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qts, with_qts, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qtp, with_qtp, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qps, with_qps, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 3, set_qpt, with_qpt, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_stpq, with_stpq, 0, 1, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_stqp, with_stqp, 0, 1, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_sptq, with_sptq, 0, 2, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_spqt, with_spqt, 0, 2, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_sqtp, with_sqtp, 0, 3, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_sqpt, with_sqpt, 0, 3, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tspq, with_tspq, 1, 0, 2, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tsqp, with_tsqp, 1, 0, 3, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tpsq, with_tpsq, 1, 2, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tpqs, with_tpqs, 1, 2, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tqsp, with_tqsp, 1, 3, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_tqps, with_tqps, 1, 3, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_pstq, with_pstq, 2, 0, 1, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_psqt, with_psqt, 2, 0, 3, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_ptsq, with_ptsq, 2, 1, 0, 3);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_ptqs, with_ptqs, 2, 1, 3, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_pqst, with_pqst, 2, 3, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_pqts, with_pqts, 2, 3, 1, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qstp, with_qstp, 3, 0, 1, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qspt, with_qspt, 3, 0, 2, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qtsp, with_qtsp, 3, 1, 0, 2);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qtps, with_qtps, 3, 1, 2, 0);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qpst, with_qpst, 3, 2, 0, 1);
+impl_xyzw_setter!(min_vector_dimension 4; 4, set_qpts, with_qpts, 3, 2, 1, 0);
+
 #[cfg(test)]
 mod xyzw_accessor_test {
     use super::*;
@@ -486,4 +1412,62 @@ mod xyzw_accessor_test {
         let v = Vector4::new(1, 2, 3, 4);
         assert_eq!(v.wzyx(), [4, 3, 2, 1].into());
     }
+
+    #[test]
+    fn set() {
+        let mut v = Vector4::new(1, 2, 3, 4);
+        v.set_x(5);
+        assert_eq!(v, Vector4::new(5, 2, 3, 4));
+        v.set_yz([6, 7]);
+        assert_eq!(v, Vector4::new(5, 6, 7, 4));
+        v.set_wzy(Vector3::new(8, 9, 10));
+        assert_eq!(v, Vector4::new(5, 10, 9, 8));
+        v.set_xyzw((1, 2, 3, 4));
+        assert_eq!(v, Vector4::new(1, 2, 3, 4));
+
+        let mut v = Vector1::new(1);
+        v.set_x(2);
+        assert_eq!(v.x(), 2);
+    }
+
+    #[test]
+    fn with() {
+        let v = Vector3::new(1, 2, 3);
+        assert_eq!(v.with_x(5), Vector3::new(5, 2, 3));
+        assert_eq!(v.with_yz([6, 7]), Vector3::new(1, 6, 7));
+        assert_eq!(v.with_zx(Vector2::new(8, 9)), Vector3::new(9, 2, 8));
+        assert_eq!(v.with_z(0).with_y(0), Vector3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut v = Vector4::new(1, 2, 3, 4);
+        *v.x_mut() += 10;
+        *v.w_mut() = 0;
+        assert_eq!(v, Vector4::new(11, 2, 3, 0));
+        *v.g_mut() = 5;
+        *v.p_mut() = 6;
+        assert_eq!(v, Vector4::new(11, 5, 6, 0));
+    }
+
+    #[test]
+    fn rgba() {
+        let mut c = Vector4::new(1, 2, 3, 4);
+        assert_eq!(c.r(), 1);
+        assert_eq!(c.a(), 4);
+        assert_eq!(c.rgb(), [1, 2, 3].into());
+        assert_eq!(c.bgra(), [3, 2, 1, 4].into());
+        c.set_rgb([5, 6, 7]);
+        assert_eq!(c.with_a(8), Vector4::new(5, 6, 7, 8));
+    }
+
+    #[test]
+    fn stpq() {
+        let mut uv = Vector2::new(1, 2);
+        assert_eq!(uv.s(), 1);
+        assert_eq!(uv.ts(), [2, 1].into());
+        uv.set_t(3);
+        assert_eq!(uv.st(), [1, 3].into());
+        assert_eq!(Vector4::new(1, 2, 3, 4).pq(), [3, 4].into());
+    }
 }
@@ -0,0 +1,310 @@
+use crate::math::vector::*;
+use num::{Float, Zero};
+use std::ops;
+
+// `LANES` vectors stored as structure of arrays: lane `i` is the vector
+// `[x[i], y[i], z[i]]`. Every operation works lane by lane, and results that
+// are scalars per vector, like `dot` or `length`, are one `Vector` of lanes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WideVector3<T: VectorElement, const LANES: usize> {
+    pub x: Vector<T, LANES>,
+    pub y: Vector<T, LANES>,
+    pub z: Vector<T, LANES>,
+}
+
+pub type WideVector3x4<T> = WideVector3<T, 4>;
+pub type WideVector3x8<T> = WideVector3<T, 8>;
+
+impl<T: VectorElement + Eq, const LANES: usize> Eq for WideVector3<T, LANES> {}
+
+impl<T: VectorElement, const LANES: usize> Default for WideVector3<T, LANES> {
+    fn default() -> Self {
+        Self::splat(Vector::<T, 3>::default())
+    }
+}
+
+impl<T: VectorElement, const LANES: usize> WideVector3<T, LANES> {
+    pub fn new(x: Vector<T, LANES>, y: Vector<T, LANES>, z: Vector<T, LANES>) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn splat(v: impl VectorLike<T, 3>) -> Self {
+        Self::new(
+            Vector::from([*v.get(0); LANES]),
+            Vector::from([*v.get(1); LANES]),
+            Vector::from([*v.get(2); LANES]),
+        )
+    }
+
+    pub fn pack(vectors: [Vector<T, 3>; LANES]) -> Self {
+        let mut result = Self::default();
+        for (i, v) in vectors.iter().enumerate() {
+            result.set_lane(i, *v);
+        }
+        result
+    }
+
+    pub fn unpack(&self) -> [Vector<T, 3>; LANES] {
+        let mut result = [Vector::<T, 3>::default(); LANES];
+        for (i, v) in result.iter_mut().enumerate() {
+            *v = self.lane(i);
+        }
+        result
+    }
+
+    pub fn lane(&self, i: usize) -> Vector<T, 3> {
+        Vector::<T, 3>::new(self.x[i], self.y[i], self.z[i])
+    }
+
+    pub fn set_lane(&mut self, i: usize, v: impl VectorLike<T, 3>) {
+        self.x[i] = *v.get(0);
+        self.y[i] = *v.get(1);
+        self.z[i] = *v.get(2);
+    }
+
+    // Takes the lanes of `if_true` where `mask` is set and those of `if_false`
+    // elsewhere.
+    pub fn select(mask: [bool; LANES], if_true: Self, if_false: Self) -> Self {
+        let pick = |a: Vector<T, LANES>, b: Vector<T, LANES>| {
+            let mut result = b;
+            for i in 0..LANES {
+                if mask[i] {
+                    result[i] = a[i];
+                }
+            }
+            result
+        };
+        Self::new(
+            pick(if_true.x, if_false.x),
+            pick(if_true.y, if_false.y),
+            pick(if_true.z, if_false.z),
+        )
+    }
+
+    pub fn dot(&self, other: Self) -> Vector<T, LANES> {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn length_squared(&self) -> Vector<T, LANES> {
+        self.dot(*self)
+    }
+
+    pub fn cross(&self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T: VectorElement + Float, const LANES: usize> WideVector3<T, LANES> {
+    pub fn length(&self) -> Vector<T, LANES> {
+        self.length_squared().sqrt()
+    }
+
+    // Lanes of zero length stay zero, like `Vector::normalized`.
+    pub fn normalized(&self) -> Self {
+        let length = self.length();
+        let inverse = length.map(|e| if e.is_zero() { T::zero() } else { e.recip() });
+        *self * inverse
+    }
+}
+
+impl<T: VectorElement, const LANES: usize> From<[Vector<T, 3>; LANES]> for WideVector3<T, LANES> {
+    fn from(vectors: [Vector<T, 3>; LANES]) -> Self {
+        Self::pack(vectors)
+    }
+}
+
+impl<T: VectorElement, const LANES: usize> From<WideVector3<T, LANES>> for [Vector<T, 3>; LANES] {
+    fn from(v: WideVector3<T, LANES>) -> Self {
+        v.unpack()
+    }
+}
+
+impl<T: VectorElement, const LANES: usize> Zero for WideVector3<T, LANES> {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+}
+
+macro_rules! impl_ops {
+    ($trait_name: ident, $function_name: ident, $assign_trait: ident, $assign_function: ident, $op: tt) => {
+        impl<T: VectorElement, const LANES: usize> ops::$trait_name for WideVector3<T, LANES> {
+            type Output = Self;
+
+            fn $function_name(self, rhs: Self) -> Self::Output {
+                Self::new(self.x $op rhs.x, self.y $op rhs.y, self.z $op rhs.z)
+            }
+        }
+
+        // Scales each lane by the matching lane of `rhs`.
+        impl<T: VectorElement, const LANES: usize> ops::$trait_name<Vector<T, LANES>>
+            for WideVector3<T, LANES>
+        {
+            type Output = Self;
+
+            fn $function_name(self, rhs: Vector<T, LANES>) -> Self::Output {
+                Self::new(self.x $op rhs, self.y $op rhs, self.z $op rhs)
+            }
+        }
+
+        impl<T: VectorElement, const LANES: usize> ops::$assign_trait for WideVector3<T, LANES> {
+            fn $assign_function(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_ops!(Add, add, AddAssign, add_assign, +);
+impl_ops!(Sub, sub, SubAssign, sub_assign, -);
+impl_ops!(Mul, mul, MulAssign, mul_assign, *);
+impl_ops!(Div, div, DivAssign, div_assign, /);
+
+impl<T: VectorElement, const LANES: usize> ops::Mul<T> for WideVector3<T, LANES> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: VectorElement, const LANES: usize> ops::Div<T> for WideVector3<T, LANES> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T, const LANES: usize> ops::Neg for WideVector3<T, LANES>
+where
+    T: VectorElement + ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::Sphere;
+
+    type Vector3 = Vector<f32, 3>;
+    type Vector4 = Vector<f32, 4>;
+
+    fn vectors() -> [Vector3; 4] {
+        [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-4.0, 0.5, 2.0),
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(3.0, -1.0, -2.5),
+        ]
+    }
+
+    fn others() -> [Vector3; 4] {
+        [
+            Vector3::new(0.5, -1.0, 2.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(2.0, 3.0, 4.0),
+            Vector3::new(-6.0, 0.25, 1.0),
+        ]
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let w = WideVector3x4::pack(vectors());
+        assert_eq!(w.x, Vector4::new(1.0, -4.0, 0.0, 3.0));
+        assert_eq!(w.unpack(), vectors());
+        assert_eq!(w.lane(1), vectors()[1]);
+        let array: [Vector3; 4] = w.into();
+        assert_eq!(WideVector3::from(array), w);
+
+        let mut w = WideVector3x8::splat([1, 2, 3]);
+        w.set_lane(5, [4, 5, 6]);
+        assert_eq!(w.lane(5), Vector::<i32, 3>::new(4, 5, 6));
+        assert_eq!(w.lane(4), Vector::<i32, 3>::new(1, 2, 3));
+    }
+
+    #[test]
+    fn arithmetic_matches_per_lane() {
+        let (a, b) = (vectors(), others());
+        let (wa, wb) = (WideVector3::pack(a), WideVector3::pack(b));
+        let scale = Vector4::new(2.0, -1.0, 0.5, 3.0);
+        for i in 0..4 {
+            assert_eq!((wa + wb).lane(i), a[i] + b[i]);
+            assert_eq!((wa - wb).lane(i), a[i] - b[i]);
+            assert_eq!((wa * wb).lane(i), a[i] * b[i]);
+            assert_eq!((wa / wb).lane(i), a[i] / b[i]);
+            assert_eq!((wa * scale).lane(i), a[i] * scale[i]);
+            assert_eq!((wa * 2.0).lane(i), a[i] * 2.0);
+            assert_eq!((-wa).lane(i), -a[i]);
+            assert_eq!(wa.dot(wb)[i], a[i].dot(b[i]));
+            assert_eq!(wa.cross(wb).lane(i), a[i].cross(b[i]));
+            assert!((wa.length()[i] - a[i].length()).abs() < 1.0e-6);
+            assert!((wa.normalized().lane(i) - a[i].normalized()).length() < 1.0e-6);
+        }
+        let mut w = wa;
+        w += wb;
+        w -= wb;
+        assert_eq!(w, wa);
+        assert_eq!(wa.normalized().lane(2), Vector3::zero());
+    }
+
+    #[test]
+    fn select() {
+        let (a, b) = (vectors(), others());
+        let w = WideVector3::select(
+            [true, false, false, true],
+            WideVector3::pack(a),
+            WideVector3::pack(b),
+        );
+        assert_eq!(w.unpack(), [a[0], b[1], b[2], a[3]]);
+    }
+
+    // Intersects four rays with one sphere at once.
+    #[test]
+    fn rays_against_sphere() {
+        let sphere = Sphere::new([0.0_f32, 0.0, -5.0], 1.0);
+        let origins = WideVector3x4::splat([0.0, 0.0, 0.0]);
+        let directions = WideVector3x4::pack([
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 1.0, -5.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(1.0, 0.0, -1.0),
+        ])
+        .normalized();
+
+        let oc = origins - WideVector3::splat(sphere.center().coords());
+        let b = oc.dot(directions);
+        let c = oc.length_squared() - Vector4::from([sphere.radius() * sphere.radius(); 4]);
+        let discriminant = b * b - c;
+        let mut hit = [false; 4];
+        let mut t = Vector4::zero();
+        for i in 0..4 {
+            t[i] = -b[i] - discriminant[i].max(0.0).sqrt();
+            hit[i] = discriminant[i] >= 0.0 && t[i] > 0.0;
+        }
+        assert_eq!(hit, [true, true, false, false]);
+
+        let points = WideVector3::select(
+            hit,
+            origins + directions * t,
+            WideVector3::splat([f32::NAN; 3]),
+        );
+        assert!((points.lane(0) - Vector3::new(0.0, 0.0, -4.0)).length() < 1.0e-5);
+        let on_sphere = (points.lane(1) - Vector3::new(0.0, 0.0, -5.0)).length();
+        assert!((on_sphere - 1.0).abs() < 1.0e-5);
+        assert!(points.lane(2).x().is_nan());
+    }
+}
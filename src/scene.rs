@@ -0,0 +1,373 @@
+use crate::math::*;
+use num::{Float, One, Zero};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LocalTransform<T: MatrixElement + Float> {
+    Trs {
+        translation: Vector<T, 3>,
+        rotation: Quaternion<T>,
+        scale: Vector<T, 3>,
+    },
+    Matrix(Matrix<T, 4, 4>),
+}
+
+impl<T: MatrixElement + Float> Default for LocalTransform<T> {
+    fn default() -> Self {
+        Self::Trs {
+            translation: Vector::zero(),
+            rotation: Quaternion::identity(),
+            scale: Vector::one(),
+        }
+    }
+}
+
+impl<T: MatrixElement + Float> LocalTransform<T> {
+    pub fn to_matrix(&self) -> Matrix<T, 4, 4> {
+        match *self {
+            Self::Trs {
+                translation,
+                rotation,
+                scale,
+            } => Matrix::from_trs(translation, rotation, scale),
+            Self::Matrix(m) => m,
+        }
+    }
+}
+
+impl<T: MatrixElement + Float> From<Matrix<T, 4, 4>> for LocalTransform<T> {
+    fn from(m: Matrix<T, 4, 4>) -> Self {
+        Self::Matrix(m)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReparentError {
+    Cycle,
+    SingularParent,
+}
+
+impl Display for ReparentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ReparentError::Cycle => "new parent is the node itself or one of its descendants",
+            ReparentError::SingularParent => "world matrix of the new parent is not invertible",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ReparentError {}
+
+#[derive(Clone, Debug)]
+struct Node<T: MatrixElement + Float> {
+    local: LocalTransform<T>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    world: Matrix<T, 4, 4>,
+    dirty: bool,
+}
+
+// World matrices follow the row-vector convention: `world = local * parent_world`.
+//
+// A node is marked dirty together with its whole subtree, and a world matrix
+// is only recomputed after the ones of its ancestors. So a dirty node never
+// has a clean descendant, which lets marking stop at nodes that are already
+// dirty and keeps every update proportional to the nodes it touches.
+#[derive(Clone, Debug, Default)]
+pub struct Scene<T: MatrixElement + Float> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: MatrixElement + Float> Scene<T> {
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add(&mut self, local: impl Into<LocalTransform<T>>, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            local: local.into(),
+            parent,
+            children: Vec::new(),
+            world: Matrix::identity(),
+            dirty: true,
+        });
+        if let Some(parent) = parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        id
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.parent.is_none())
+            .map(|(i, _)| NodeId(i))
+    }
+
+    pub fn local(&self, id: NodeId) -> &LocalTransform<T> {
+        &self.nodes[id.0].local
+    }
+
+    pub fn set_local(&mut self, id: NodeId, local: impl Into<LocalTransform<T>>) {
+        self.nodes[id.0].local = local.into();
+        self.mark_dirty(id);
+    }
+
+    fn mark_dirty(&mut self, id: NodeId) {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = &mut self.nodes[id.0];
+            if !node.dirty {
+                node.dirty = true;
+                stack.extend(node.children.iter().copied());
+            }
+        }
+    }
+
+    pub fn world_matrix(&mut self, id: NodeId) -> Matrix<T, 4, 4> {
+        let mut chain = Vec::new();
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if !self.nodes[id.0].dirty {
+                break;
+            }
+            chain.push(id);
+            current = self.nodes[id.0].parent;
+        }
+        let mut parent_world = current.map(|id| self.nodes[id.0].world);
+        for id in chain.into_iter().rev() {
+            let node = &mut self.nodes[id.0];
+            let local = node.local.to_matrix();
+            node.world = match parent_world {
+                Some(parent_world) => local * parent_world,
+                None => local,
+            };
+            node.dirty = false;
+            parent_world = Some(node.world);
+        }
+        self.nodes[id.0].world
+    }
+
+    pub fn update_world_matrices(&mut self) {
+        let order: Vec<NodeId> = self.iter_depth_first().collect();
+        for id in order {
+            if self.nodes[id.0].dirty {
+                self.world_matrix(id);
+            }
+        }
+    }
+
+    pub fn is_ancestor(&self, ancestor: NodeId, id: NodeId) -> bool {
+        let mut current = self.nodes[id.0].parent;
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.nodes[parent.0].parent;
+        }
+        false
+    }
+
+    // The local transform is recomputed so that the world transform of the
+    // node does not change. It stays a TRS if the new local matrix can be
+    // decomposed into one.
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) -> Result<(), ReparentError> {
+        if let Some(parent) = parent {
+            if parent == id || self.is_ancestor(id, parent) {
+                return Err(ReparentError::Cycle);
+            }
+        }
+        let world = self.world_matrix(id);
+        let local = match parent {
+            Some(parent) => {
+                let parent_world = self.world_matrix(parent);
+                let inverse = parent_world
+                    .inverse()
+                    .ok_or(ReparentError::SingularParent)?;
+                world * inverse
+            }
+            None => world,
+        };
+        let local = match (self.nodes[id.0].local, local.decompose()) {
+            (LocalTransform::Trs { .. }, Some((translation, rotation, scale))) => {
+                LocalTransform::Trs {
+                    translation,
+                    rotation,
+                    scale,
+                }
+            }
+            _ => LocalTransform::Matrix(local),
+        };
+
+        if let Some(old_parent) = self.nodes[id.0].parent {
+            self.nodes[old_parent.0]
+                .children
+                .retain(|&child| child != id);
+        }
+        if let Some(parent) = parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        let node = &mut self.nodes[id.0];
+        node.parent = parent;
+        node.local = local;
+        self.mark_dirty(id);
+        Ok(())
+    }
+
+    pub fn iter_depth_first(&self) -> DepthFirst<'_, T> {
+        let mut stack: Vec<NodeId> = self.roots().collect();
+        stack.reverse();
+        DepthFirst { scene: self, stack }
+    }
+}
+
+pub struct DepthFirst<'a, T: MatrixElement + Float> {
+    scene: &'a Scene<T>,
+    stack: Vec<NodeId>,
+}
+
+impl<T: MatrixElement + Float> Iterator for DepthFirst<'_, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.stack.pop()?;
+        self.stack
+            .extend(self.scene.children(id).iter().rev().copied());
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::approx::assert_approx_eq;
+
+    type Vector3 = Vector<f64, 3>;
+    type Matrix4 = Matrix<f64, 4, 4>;
+
+    fn trs(translation: [f64; 3], angle: f64, scale: f64) -> LocalTransform<f64> {
+        LocalTransform::Trs {
+            translation: translation.into(),
+            rotation: Quaternion::from_axis_angle([0.0, 0.0, 1.0], angle),
+            scale: Vector3::one() * scale,
+        }
+    }
+
+    #[test]
+    fn world_matrix() {
+        let mut scene = Scene::new();
+        let root = scene.add(trs([1.0, 0.0, 0.0], 0.0, 2.0), None);
+        let child = scene.add(Matrix4::translation([0.0, 1.0, 0.0]), Some(root));
+        let grandchild = scene.add(trs([0.0, 0.0, 1.0], 0.5, 1.0), Some(child));
+        assert_eq!(scene.len(), 3);
+
+        let expected = scene.local(grandchild).to_matrix()
+            * Matrix4::translation([0.0, 1.0, 0.0])
+            * scene.local(root).to_matrix();
+        assert_approx_eq(scene.world_matrix(grandchild), expected, 1.0e-10);
+        let p = scene.world_matrix(child).transform_point(Vector3::zero());
+        assert!((p - Vector3::new(1.0, 2.0, 0.0)).length() < 1.0e-10);
+    }
+
+    #[test]
+    fn dirty_propagation() {
+        let mut scene = Scene::new();
+        let root = scene.add(trs([1.0, 0.0, 0.0], 0.0, 1.0), None);
+        let a = scene.add(LocalTransform::default(), Some(root));
+        let b = scene.add(trs([0.0, 1.0, 0.0], 0.0, 1.0), Some(a));
+        let other = scene.add(LocalTransform::default(), None);
+        scene.update_world_matrices();
+        assert!(scene.nodes.iter().all(|node| !node.dirty));
+
+        scene.set_local(a, Matrix4::translation([0.0, 0.0, 5.0]));
+        assert!(!scene.nodes[root.0].dirty);
+        assert!(scene.nodes[a.0].dirty && scene.nodes[b.0].dirty);
+        assert!(!scene.nodes[other.0].dirty);
+
+        let p = scene.world_matrix(b).transform_point(Vector3::zero());
+        assert!((p - Vector3::new(1.0, 1.0, 5.0)).length() < 1.0e-10);
+        assert!(!scene.nodes[a.0].dirty && !scene.nodes[b.0].dirty);
+    }
+
+    #[test]
+    fn set_parent_keeps_world() {
+        let mut scene = Scene::new();
+        let a = scene.add(trs([1.0, 2.0, 3.0], 0.7, 2.0), None);
+        let b = scene.add(trs([-4.0, 0.0, 1.0], -0.3, 0.5), None);
+        let c = scene.add(trs([0.0, 1.0, 0.0], 1.1, 1.0), Some(a));
+        let d = scene.add(trs([1.0, 0.0, 0.0], 0.0, 1.0), Some(c));
+        let world_c = scene.world_matrix(c);
+        let world_d = scene.world_matrix(d);
+
+        scene.set_parent(c, Some(b)).unwrap();
+        assert_eq!(scene.parent(c), Some(b));
+        assert_eq!(scene.children(a), &[]);
+        assert_eq!(scene.children(b), &[c]);
+        assert!(matches!(scene.local(c), LocalTransform::Trs { .. }));
+        assert_approx_eq(scene.world_matrix(c), world_c, 1.0e-10);
+        assert_approx_eq(scene.world_matrix(d), world_d, 1.0e-10);
+
+        scene.set_parent(c, None).unwrap();
+        assert_eq!(scene.parent(c), None);
+        assert_approx_eq(scene.world_matrix(d), world_d, 1.0e-10);
+    }
+
+    #[test]
+    fn set_parent_errors() {
+        let mut scene = Scene::new();
+        let a = scene.add(LocalTransform::default(), None);
+        let b = scene.add(LocalTransform::default(), Some(a));
+        let c = scene.add(LocalTransform::default(), Some(b));
+        assert_eq!(scene.set_parent(a, Some(c)), Err(ReparentError::Cycle));
+        assert_eq!(scene.set_parent(a, Some(a)), Err(ReparentError::Cycle));
+
+        let flat = scene.add(Matrix4::scaling([1.0, 0.0, 1.0]), None);
+        assert_eq!(
+            scene.set_parent(c, Some(flat)),
+            Err(ReparentError::SingularParent)
+        );
+        assert_eq!(scene.parent(c), Some(b));
+    }
+
+    #[test]
+    fn iter_depth_first() {
+        let mut scene = Scene::<f64>::new();
+        let a = scene.add(LocalTransform::default(), None);
+        let b = scene.add(LocalTransform::default(), None);
+        let a1 = scene.add(LocalTransform::default(), Some(a));
+        let b1 = scene.add(LocalTransform::default(), Some(b));
+        let a2 = scene.add(LocalTransform::default(), Some(a));
+        let a11 = scene.add(LocalTransform::default(), Some(a1));
+        let order: Vec<_> = scene.iter_depth_first().collect();
+        assert_eq!(order, vec![a, a1, a11, a2, b, b1]);
+        assert_eq!(scene.roots().collect::<Vec<_>>(), vec![a, b]);
+    }
+}